@@ -3,7 +3,7 @@
 //! This performs a lookup only, no additional translation files are provided
 
 use dioxus::prelude::*;
-use dioxus_i18n::{prelude::*, tid};
+use dioxus_i18n::{prelude::*, ta, tid};
 use unic_langid::langid;
 
 use std::path::PathBuf;
@@ -66,6 +66,10 @@ fn Body() -> Element {
                     td { }
                     td { {tid!("message-with-attributes.title")} }
                 }
+                tr {
+                    td { "Message with attributes: all attributes" }
+                    td { input { ..ta!("message-with-attributes") } }
+                }
                 tr {
                     td { "Message with attributes: not existing" }
                     td { {tid!("message-with-attributes.not-existing")} }
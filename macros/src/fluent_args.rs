@@ -0,0 +1,108 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Field, Fields, LitStr};
+
+pub(crate) fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let mut kebab_case = false;
+    for attribute in attributes(&input.attrs, "fluent") {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") {
+                let case: LitStr = meta.value()?.parse()?;
+                match case.value().as_str() {
+                    "kebab-case" => kebab_case = true,
+                    _ => return Err(meta.error("only `rename_all = \"kebab-case\"` is supported")),
+                }
+                Ok(())
+            } else {
+                Err(meta.error("unsupported fluent attribute"))
+            }
+        })?;
+    }
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "FluentArgs can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "FluentArgs can only be derived for structs with named fields",
+        ));
+    };
+
+    let mut args = vec![];
+    for field in &fields.named {
+        let Some(name) = arg_name(field, "fluent", || match kebab_case {
+            true => ident_name(field).replace('_', "-"),
+            false => ident_name(field),
+        })?
+        else {
+            continue;
+        };
+        let ident = &field.ident;
+        args.push(set_arg(&name, quote! { &self.#ident }));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::dioxus_i18n::prelude::ToFluentArgs for #ident #ty_generics #where_clause {
+            fn to_fluent_args(&self) -> ::dioxus_i18n::fluent::FluentArgs<'_> {
+                let mut args = ::dioxus_i18n::fluent::FluentArgs::new();
+                #(#args)*
+                args
+            }
+        }
+    })
+}
+
+/// The attributes of the derive macro, e.g. `#[fluent(...)]`.
+pub(crate) fn attributes<'a>(
+    attributes: &'a [Attribute],
+    name: &'a str,
+) -> impl Iterator<Item = &'a Attribute> {
+    attributes
+        .iter()
+        .filter(move |attribute| attribute.path().is_ident(name))
+}
+
+/// The name of the argument for `field`, from its `rename` attribute or else `default`, or
+/// `None` if the field is skipped.
+pub(crate) fn arg_name(
+    field: &Field,
+    attribute_name: &str,
+    default: impl FnOnce() -> String,
+) -> syn::Result<Option<String>> {
+    let mut skip = false;
+    let mut rename = None;
+    for attribute in attributes(&field.attrs, attribute_name) {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skip = true;
+                Ok(())
+            } else if meta.path.is_ident("rename") {
+                rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else {
+                Err(meta.error(format!("unsupported {} attribute", attribute_name)))
+            }
+        })?;
+    }
+
+    Ok((!skip).then(|| rename.unwrap_or_else(default)))
+}
+
+/// The name of a named field, without the `r#` of raw identifiers.
+pub(crate) fn ident_name(field: &Field) -> String {
+    let ident = field.ident.as_ref().expect("named fields have identifiers");
+    ident.to_string().trim_start_matches("r#").to_string()
+}
+
+/// Set argument `name` of `args` to a clone of the referenced `value`.
+pub(crate) fn set_arg(name: &str, value: TokenStream) -> TokenStream {
+    quote! {
+        args.set(#name, ::std::clone::Clone::clone(#value));
+    }
+}
@@ -0,0 +1,61 @@
+//! Derive macros for [dioxus-i18n](https://github.com/dioxus-community/dioxus-i18n), enabled
+//! with its `derive` feature.
+
+mod fluent_args;
+mod localize;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derive `ToFluentArgs`, which turns the fields of a struct into the arguments of a
+/// translation, e.g. `t!("greeting", args: &user)`.
+///
+/// Each field is passed as an argument named after the field, and its value must implement
+/// `Clone` and `Into<FluentValue>`, e.g. strings and numbers. Attributes:
+///
+/// - `#[fluent(rename_all = "kebab-case")]` on the struct names the arguments in kebab-case,
+///   e.g. `item_count` as `item-count`.
+/// - `#[fluent(rename = "name")]` on a field sets the name of its argument.
+/// - `#[fluent(skip)]` on a field does not pass it.
+#[proc_macro_derive(FluentArgs, attributes(fluent))]
+pub fn derive_fluent_args(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    fluent_args::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `Localize`, which translates an enum variant, or a struct, into the selected
+/// language.
+///
+/// The message id is the kebab-case name of the type followed by that of the variant, e.g.
+/// `order-status-shipped` for `OrderStatus::Shipped`, or the name of the type for a struct.
+/// The fields are passed as arguments, as with `FluentArgs`, where tuple fields are named
+/// `arg0`, `arg1`, ... Attributes:
+///
+/// - `#[localize(prefix = "status")]` on an enum replaces the name of the type in the message
+///   ids, e.g. `status-shipped`.
+/// - `#[localize(id = "message-id")]` on a variant or struct sets its message id, which may
+///   include a namespace or attribute, e.g. `orders:status.shipped`.
+/// - `#[localize(rename = "name")]` on a field sets the name of its argument.
+/// - `#[localize(skip)]` on a field does not pass it.
+#[proc_macro_derive(Localize, attributes(localize))]
+pub fn derive_localize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    localize::derive(input, localize::Target::Localize)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `LocalizedError`, which translates an error into the selected language, falling back
+/// to its `Display` implementation when the translation fails.
+///
+/// Message ids and arguments are those of `Localize`, with the same attributes, except that the
+/// fields marked with `#[source]` or `#[from]` (as for `thiserror`) are not passed as arguments.
+#[proc_macro_derive(LocalizedError, attributes(localize))]
+pub fn derive_localized_error(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    localize::derive(input, localize::Target::LocalizedError)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,235 @@
+use super::fluent_args::{arg_name, attributes, ident_name, set_arg};
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, Data, DeriveInput, Field, Fields, LitStr};
+
+/// The trait implemented by [`derive`].
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Target {
+    Localize,
+    LocalizedError,
+}
+
+impl Target {
+    /// The trait, and its method translating the value.
+    fn items(self) -> (TokenStream, TokenStream) {
+        match self {
+            Self::Localize => (
+                quote! { ::dioxus_i18n::prelude::Localize },
+                quote! { try_localize },
+            ),
+            Self::LocalizedError => (
+                quote! { ::dioxus_i18n::prelude::LocalizedError },
+                quote! { try_localize_error },
+            ),
+        }
+    }
+
+    /// The `I18n` method translating the message.
+    fn lookup(self) -> TokenStream {
+        match self {
+            Self::Localize => quote! { try_translate_with_args },
+            // Errors fall back to `Display`, rather than to the missing translation policy.
+            Self::LocalizedError => quote! { try_translate_without_policy },
+        }
+    }
+
+    /// Whether `field` is not passed as an argument, besides `#[localize(skip)]`.
+    fn skips(self, field: &Field) -> bool {
+        // The source of an error, as marked for `thiserror`, is rarely a Fluent value.
+        self == Self::LocalizedError
+            && field
+                .attrs
+                .iter()
+                .any(|attr| attr.path().is_ident("source") || attr.path().is_ident("from"))
+    }
+}
+
+pub(crate) fn derive(input: DeriveInput, target: Target) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let type_name = kebab_case(&ident.to_string());
+    let (prefix, id) = parse_attributes(&input.attrs)?;
+
+    let body = match &input.data {
+        Data::Enum(data) => {
+            if id.is_some() {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "`id` is set on the variants of an enum",
+                ));
+            }
+            let prefix = prefix.unwrap_or(type_name);
+            let arms = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let (prefix_attribute, id) = parse_attributes(&variant.attrs)?;
+                    if prefix_attribute.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            &variant.ident,
+                            "`prefix` is set on the enum",
+                        ));
+                    }
+                    let id = id.unwrap_or_else(|| {
+                        format!("{}-{}", prefix, kebab_case(&variant.ident.to_string()))
+                    });
+                    let variant_ident = &variant.ident;
+                    let (pattern, translate) = bind_fields(&variant.fields, &id, target)?;
+                    Ok(quote! { Self::#variant_ident #pattern => { #translate } })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            match arms.is_empty() {
+                true => quote! { match *self {} },
+                false => quote! { match self { #(#arms)* } },
+            }
+        }
+        Data::Struct(data) => {
+            if prefix.is_some() {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "`prefix` is only supported on enums",
+                ));
+            }
+            let id = id.unwrap_or(type_name);
+            let (pattern, translate) = bind_fields(&data.fields, &id, target)?;
+            quote! {
+                let Self #pattern = self;
+                #translate
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "only enums and structs are supported",
+            ))
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let (trait_path, method) = target.items();
+    Ok(quote! {
+        impl #impl_generics #trait_path for #ident #ty_generics #where_clause {
+            fn #method(
+                &self,
+                i18n: &::dioxus_i18n::prelude::I18n,
+            ) -> ::std::result::Result<::std::string::String, ::dioxus_i18n::prelude::DioxusI18nError> {
+                #body
+            }
+        }
+    })
+}
+
+/// The `prefix` and `id` of `#[localize(...)]` attributes.
+fn parse_attributes(attrs: &[Attribute]) -> syn::Result<(Option<String>, Option<String>)> {
+    let mut prefix = None;
+    let mut id = None;
+    for attribute in attributes(attrs, "localize") {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("prefix") {
+                prefix = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else if meta.path.is_ident("id") {
+                id = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else {
+                Err(meta.error("unsupported localize attribute"))
+            }
+        })?;
+    }
+    Ok((prefix, id))
+}
+
+/// The pattern binding `fields`, and the translation of message `id` with the bound fields
+/// as arguments.
+fn bind_fields(
+    fields: &Fields,
+    id: &str,
+    target: Target,
+) -> syn::Result<(TokenStream, TokenStream)> {
+    let mut bindings = vec![];
+    let mut args = vec![];
+    for (index, field) in fields.iter().enumerate() {
+        let (binding, name) = match &field.ident {
+            Some(_) => (
+                format_ident!("__{}", ident_name(field)),
+                arg_name(field, "localize", || ident_name(field))?,
+            ),
+            None => (
+                format_ident!("__arg{}", index),
+                arg_name(field, "localize", || format!("arg{}", index))?,
+            ),
+        };
+        let Some(name) = name.filter(|_| !target.skips(field)) else {
+            bindings.push(None);
+            continue;
+        };
+        args.push(set_arg(&name, quote! { #binding }));
+        bindings.push(Some(binding));
+    }
+
+    let pattern = match fields {
+        Fields::Named(_) => {
+            let bindings = fields.iter().zip(&bindings).filter_map(|(field, binding)| {
+                let ident = &field.ident;
+                binding.as_ref().map(|binding| quote! { #ident: #binding })
+            });
+            quote! { { #(#bindings,)* .. } }
+        }
+        Fields::Unnamed(_) => {
+            let bindings = bindings.iter().map(|binding| match binding {
+                Some(binding) => quote! { #binding },
+                None => quote! { _ },
+            });
+            quote! { ( #(#bindings),* ) }
+        }
+        Fields::Unit => quote! {},
+    };
+
+    let lookup = target.lookup();
+    let translate = match args.is_empty() {
+        true => quote! { i18n.#lookup(#id, None) },
+        false => quote! {
+            let mut args = ::dioxus_i18n::fluent::FluentArgs::new();
+            #(#args)*
+            i18n.#lookup(#id, Some(&args))
+        },
+    };
+    Ok((pattern, translate))
+}
+
+/// The kebab-case of a Rust type or variant name, e.g. `http-error` for `HTTPError`.
+fn kebab_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut kebab = String::new();
+    for (index, &c) in chars.iter().enumerate() {
+        if c == '_' {
+            kebab.push('-');
+            continue;
+        }
+        if c.is_uppercase() && index > 0 {
+            let previous = chars[index - 1];
+            let after_word = previous.is_lowercase() || previous.is_ascii_digit();
+            let acronym_end = previous.is_uppercase()
+                && chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+            if after_word || acronym_end {
+                kebab.push('-');
+            }
+        }
+        kebab.extend(c.to_lowercase());
+    }
+    kebab
+}
+
+#[cfg(test)]
+mod test {
+    use super::kebab_case;
+
+    #[test]
+    fn names_are_converted_to_kebab_case() {
+        assert_eq!(kebab_case("OrderStatus"), "order-status");
+        assert_eq!(kebab_case("HTTPError"), "http-error");
+        assert_eq!(kebab_case("Utf8Error"), "utf8-error");
+        assert_eq!(kebab_case("Kg"), "kg");
+    }
+}
@@ -0,0 +1,275 @@
+//! Names of languages, for use in language selection.
+//!
+//! With the `display-names` feature, localized names of languages, scripts and regions are
+//! available from CLDR data, see [`display_names`].
+
+use unic_langid::LanguageIdentifier;
+
+#[cfg(feature = "display-names")]
+use icu_casemap::{
+    options::{TitlecaseOptions, TrailingCase},
+    TitlecaseMapper,
+};
+#[cfg(feature = "display-names")]
+use icu_experimental::displaynames::{
+    LanguageDisplayNames, LocaleDisplayNamesFormatter, RegionDisplayNames, ScriptDisplayNames,
+};
+#[cfg(feature = "display-names")]
+use icu_locale_core::Locale;
+#[cfg(feature = "display-names")]
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+/// The autonym of the language of `id`, i.e. its name in the language itself, e.g. "Español".
+///
+/// Only the language subtag is considered, and only the more widely used languages are
+/// known; `None` is returned for others.
+///
+/// ```rust
+/// # use dioxus_i18n::prelude::autonym;
+/// # use unic_langid::langid;
+/// assert_eq!(autonym(&langid!("de-AT")), Some("Deutsch"));
+/// ```
+pub fn autonym(id: &LanguageIdentifier) -> Option<&'static str> {
+    let autonym = match id.language.as_str() {
+        "ar" => "العربية",
+        "bg" => "Български",
+        "bn" => "বাংলা",
+        "ca" => "Català",
+        "cs" => "Čeština",
+        "cy" => "Cymraeg",
+        "da" => "Dansk",
+        "de" => "Deutsch",
+        "el" => "Ελληνικά",
+        "en" => "English",
+        "eo" => "Esperanto",
+        "es" => "Español",
+        "et" => "Eesti",
+        "eu" => "Euskara",
+        "fa" => "فارسی",
+        "fi" => "Suomi",
+        "fr" => "Français",
+        "ga" => "Gaeilge",
+        "gl" => "Galego",
+        "he" => "עברית",
+        "hi" => "हिन्दी",
+        "hr" => "Hrvatski",
+        "hu" => "Magyar",
+        "id" => "Indonesia",
+        "is" => "Íslenska",
+        "it" => "Italiano",
+        "ja" => "日本語",
+        "ko" => "한국어",
+        "lt" => "Lietuvių",
+        "lv" => "Latviešu",
+        "ms" => "Melayu",
+        "nb" => "Norsk bokmål",
+        "nl" => "Nederlands",
+        "nn" => "Norsk nynorsk",
+        "no" => "Norsk",
+        "pl" => "Polski",
+        "pt" => "Português",
+        "ro" => "Română",
+        "ru" => "Русский",
+        "sk" => "Slovenčina",
+        "sl" => "Slovenščina",
+        "sr" => "Српски",
+        "sv" => "Svenska",
+        "sw" => "Kiswahili",
+        "ta" => "தமிழ்",
+        "th" => "ไทย",
+        "tr" => "Türkçe",
+        "uk" => "Українська",
+        "ur" => "اردو",
+        "vi" => "Tiếng Việt",
+        "zh" => "中文",
+        _ => return None,
+    };
+    Some(autonym)
+}
+
+/// A label for `id` in its own language: the autonym, followed by the region if any, e.g.
+/// "Português (BR)". The identifier itself is used for unknown languages.
+///
+/// With the `display-names` feature, the CLDR name of `id` in its own language is preferred,
+/// with its first letter in title case as the autonyms, e.g. "Português (Brasil)".
+pub fn language_label(id: &LanguageIdentifier) -> String {
+    #[cfg(feature = "display-names")]
+    if let Some(names) = display_names(id, id) {
+        return capitalize(&names.locale, id);
+    }
+
+    let Some(autonym) = autonym(id) else {
+        return id.to_string();
+    };
+    match id.region {
+        Some(region) => format!("{} ({})", autonym, region),
+        None => autonym.to_string(),
+    }
+}
+
+/// The display names of a language identifier and its subtags, see [`display_names`].
+#[cfg(feature = "display-names")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisplayNames {
+    /// The name of the whole identifier, e.g. "German (Switzerland)" or "Swiss High German".
+    pub locale: String,
+    /// The name of the language subtag, e.g. "German".
+    pub language: Option<String>,
+    /// The name of the script subtag, e.g. "Latin".
+    pub script: Option<String>,
+    /// The name of the region subtag, e.g. "Switzerland".
+    pub region: Option<String>,
+}
+
+#[cfg(feature = "display-names")]
+struct Formatters {
+    locale: LocaleDisplayNamesFormatter,
+    language: LanguageDisplayNames,
+    script: ScriptDisplayNames,
+    region: RegionDisplayNames,
+}
+
+#[cfg(feature = "display-names")]
+thread_local! {
+    static FORMATTERS: RefCell<HashMap<LanguageIdentifier, Option<Rc<Formatters>>>> =
+        RefCell::new(HashMap::new());
+}
+
+/// `name` with its first letter in title case, following the casing rules of language `id`,
+/// e.g. "İngilizce" for the Turkish "ingilizce".
+#[cfg(feature = "display-names")]
+fn capitalize(name: &str, id: &LanguageIdentifier) -> String {
+    let Some(locale) = to_locale(id) else {
+        return name.to_string();
+    };
+    let mut options = TitlecaseOptions::default();
+    options.trailing_case = Some(TrailingCase::Unchanged);
+    TitlecaseMapper::new()
+        .titlecase_segment_to_string(name, &locale.id, options)
+        .into_owned()
+}
+
+#[cfg(feature = "display-names")]
+fn to_locale(id: &LanguageIdentifier) -> Option<Locale> {
+    Locale::try_from_str(&id.to_string()).ok()
+}
+
+#[cfg(feature = "display-names")]
+fn formatters(in_language: &LanguageIdentifier) -> Option<Rc<Formatters>> {
+    FORMATTERS.with_borrow_mut(|formatters| {
+        formatters
+            .entry(in_language.clone())
+            .or_insert_with(|| {
+                let locale = to_locale(in_language)?;
+                Some(Rc::new(Formatters {
+                    locale: LocaleDisplayNamesFormatter::try_new(
+                        (&locale).into(),
+                        Default::default(),
+                    )
+                    .ok()?,
+                    language: LanguageDisplayNames::try_new((&locale).into(), Default::default())
+                        .ok()?,
+                    script: ScriptDisplayNames::try_new((&locale).into(), Default::default())
+                        .ok()?,
+                    region: RegionDisplayNames::try_new((&locale).into(), Default::default())
+                        .ok()?,
+                }))
+            })
+            .clone()
+    })
+}
+
+/// The CLDR display names of `id` in language `in_language` (requires the `display-names`
+/// feature), or `None` if there is no CLDR data for `in_language`.
+///
+/// Pass `id` as `in_language` for the names in its own language, or use
+/// [`crate::prelude::I18n::display_names`] for the names in the selected language.
+/// The CLDR data for each `in_language` is loaded once per thread.
+///
+/// ```rust
+/// # use dioxus_i18n::prelude::display_names;
+/// # use unic_langid::langid;
+/// let names = display_names(&langid!("de-CH"), &langid!("en")).unwrap();
+/// assert_eq!(names.language.as_deref(), Some("German"));
+/// assert_eq!(names.region.as_deref(), Some("Switzerland"));
+/// ```
+#[cfg(feature = "display-names")]
+pub fn display_names(
+    id: &LanguageIdentifier,
+    in_language: &LanguageIdentifier,
+) -> Option<DisplayNames> {
+    let formatters = formatters(in_language)?;
+    let locale = to_locale(id)?;
+
+    Some(DisplayNames {
+        locale: formatters.locale.of(&locale).into_owned(),
+        language: formatters
+            .language
+            .of(locale.id.language)
+            .map(str::to_string),
+        script: locale
+            .id
+            .script
+            .and_then(|script| formatters.script.of(script))
+            .map(str::to_string),
+        region: locale
+            .id
+            .region
+            .and_then(|region| formatters.region.of(region))
+            .map(str::to_string),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use unic_langid::langid;
+
+    #[cfg(not(feature = "display-names"))]
+    #[test]
+    fn language_label_includes_region() {
+        assert_eq!(language_label(&langid!("pt-BR")), "Português (BR)");
+        assert_eq!(language_label(&langid!("es")), "Español");
+    }
+
+    #[test]
+    fn language_label_of_unknown_language_is_its_id() {
+        assert_eq!(language_label(&langid!("xx-YY")), "xx-YY");
+    }
+
+    #[cfg(feature = "display-names")]
+    #[test]
+    fn language_label_uses_cldr_autonym() {
+        assert_eq!(language_label(&langid!("pt-BR")), "Português (Brasil)");
+        assert_eq!(language_label(&langid!("es")), "Español");
+    }
+
+    #[cfg(feature = "display-names")]
+    #[test]
+    fn capitalize_follows_language_rules() {
+        assert_eq!(capitalize("ingilizce", &langid!("tr")), "İngilizce");
+        assert_eq!(capitalize("ingilizce", &langid!("en")), "Ingilizce");
+    }
+
+    #[cfg(feature = "display-names")]
+    #[test]
+    fn display_names_in_another_language() {
+        let names = display_names(&langid!("sr-Latn-RS"), &langid!("de")).unwrap();
+        assert_eq!(
+            names,
+            DisplayNames {
+                locale: "Serbisch (Lateinisch, Serbien)".to_string(),
+                language: Some("Serbisch".to_string()),
+                script: Some("Lateinisch".to_string()),
+                region: Some("Serbien".to_string()),
+            }
+        );
+    }
+
+    #[cfg(feature = "display-names")]
+    #[test]
+    fn display_names_without_cldr_data() {
+        assert_eq!(display_names(&langid!("de"), &langid!("xx")), None);
+    }
+}
@@ -0,0 +1,114 @@
+//! Integration with the html document (requires the `html` feature).
+
+use crate::use_i18n::i18n;
+
+use dioxus_lib::document;
+use dioxus_lib::prelude::*;
+use unic_langid::{CharacterDirection, LanguageIdentifier};
+
+/// Keep the root document's `lang` and `dir` attributes in sync with the selected language.
+///
+/// Screen readers, hyphenation and spell-checking rely on `<html lang>`. This is supported
+/// where the document can be evaluated, i.e. on web and desktop webviews.
+pub fn use_document_lang() {
+    let i18n = i18n();
+    use_effect(move || {
+        let id = i18n.language();
+        let script = format!(
+            "document.documentElement.lang = '{}'; document.documentElement.dir = '{}';",
+            id,
+            direction(&id)
+        );
+        document::eval(&script);
+    });
+}
+
+/// Mark a sub-tree with the `lang` (and `dir`) of the locale providing message `id`.
+///
+/// When the message is not available in the selected language, its text is taken from a less
+/// specific locale or the fallback language; this component ensures that such text is
+/// announced and hyphenated in the language it is actually written in.
+///
+/// The children are wrapped in a `span`, for inline content, or in a `div` with `block: true`,
+/// e.g. around paragraphs.
+///
+/// ```rust,ignore
+/// rsx! {
+///     p { "{t!(\"welcome\")} " MessageLang { id: "motto", { t!("motto") } } }
+///     MessageLang { id: "terms-of-service", block: true, p { { t!("terms-of-service") } } }
+/// }
+/// ```
+#[component]
+pub fn MessageLang(
+    /// The message id (or `message.attribute`) whose language applies to the children.
+    id: String,
+    /// Wrap the children in a `div` rather than a `span`.
+    #[props(default)]
+    block: bool,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    children: Element,
+) -> Element {
+    let i18n = i18n();
+    let lang = i18n
+        .message_language(&id)
+        .unwrap_or_else(|| i18n.language());
+    let dir = direction(&lang);
+
+    if block {
+        rsx! {
+            div { lang: lang.to_string(), dir, ..attributes, {children} }
+        }
+    } else {
+        rsx! {
+            span { lang: lang.to_string(), dir, ..attributes, {children} }
+        }
+    }
+}
+
+fn direction(id: &LanguageIdentifier) -> &'static str {
+    match id.character_direction() {
+        CharacterDirection::RTL => "rtl",
+        _ => "ltr",
+    }
+}
+
+/// Emit a `<link rel="alternate" hreflang="...">` in the document head for every language
+/// with a locale, so that search engines can find each translation of the page.
+///
+/// ```rust,ignore
+/// rsx! {
+///     HreflangLinks {
+///         href: |id| format!("https://example.com/{id}/settings"),
+///         x_default: "https://example.com/settings",
+///     }
+/// }
+/// ```
+///
+/// As with [`document::Link`], updates to the props after the first render are not reflected.
+#[component]
+pub fn HreflangLinks(
+    /// The url of the page in the given language.
+    href: Callback<LanguageIdentifier, String>,
+    /// The url used for the `x-default` alternate, i.e. when no language matches.
+    x_default: Option<String>,
+) -> Element {
+    let languages = i18n().available_languages();
+
+    rsx! {
+        for id in languages {
+            document::Link {
+                key: "{id}",
+                rel: "alternate",
+                hreflang: id.to_string(),
+                href: href(id.clone()),
+            }
+        }
+        if let Some(x_default) = x_default {
+            document::Link {
+                rel: "alternate",
+                hreflang: "x-default",
+                href: x_default,
+            }
+        }
+    }
+}
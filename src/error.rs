@@ -1,31 +1,248 @@
+use super::localize::LocalizedError;
+use super::use_i18n::I18n;
+
+use fluent::{FluentArgs, FluentError};
+use fluent_syntax::parser::ParserError;
 use thiserror::Error;
+use unic_langid::LanguageIdentifier;
+
+use std::{fmt, path::PathBuf};
 
 #[derive(Clone, Debug, Error)]
 pub enum Error {
     #[error("invalid message id: '{0}'")]
     InvalidMessageId(String),
 
-    #[error("message id not found for key: '{0}'")]
-    MessageIdNotFound(String),
+    #[error("message id not found for key: '{key}'{}", did_you_mean(.suggestion))]
+    MessageIdNotFound {
+        key: String,
+        /// The most similar message id of the bundle, if any.
+        suggestion: Option<String>,
+    },
 
     #[error("attribute id not found for key: '{0}'")]
     AttributeIdNotFound(String),
 
+    #[error("namespace not found: '{0}'")]
+    NamespaceNotFound(String),
+
     #[error("message pattern not found for key: '{0}'")]
     MessagePatternNotFound(String),
 
-    #[error("fluent errors during lookup:\n{0}")]
-    FluentErrorsDetected(String),
+    #[error("fluent errors during lookup of '{key}' in {language}:{}", list(.errors))]
+    FluentErrorsDetected {
+        key: String,
+        language: LanguageIdentifier,
+        errors: Vec<FluentError>,
+    },
 
-    #[error("failed to read locale resource from path: {0}")]
-    LocaleResourcePathReadFailed(String),
+    #[error("failed to parse {origin} for {language}:{}", list(.errors))]
+    ResourceParseFailed {
+        language: LanguageIdentifier,
+        origin: ResourceOrigin,
+        errors: Vec<ResourceParseError>,
+    },
+
+    #[error("invalid override of '{key}' for {language}: {reason}")]
+    InvalidOverride {
+        key: String,
+        language: LanguageIdentifier,
+        reason: String,
+    },
+
+    #[error("failed to read locale resource from path '{}': {reason}", .path.display())]
+    LocaleResourcePathReadFailed { path: PathBuf, reason: String },
 
     #[error("fallback for \"{0}\" must have locale")]
     FallbackMustHaveLocale(String),
 
+    #[error("language \"{0}\" must have locale")]
+    LanguageMustHaveLocale(String),
+
+    #[error("locale already exists for language \"{0}\"")]
+    LocaleAlreadyExists(String),
+
+    #[error("locale not found for language \"{0}\"")]
+    LocaleNotFound(String),
+
+    #[error("fallback \"{0}\" cannot be removed")]
+    FallbackCannotBeRemoved(String),
+
+    #[error("invalid configuration:{}", list(.0))]
+    InvalidConfig(Vec<Error>),
+
     #[error("language id cannot be determined - reason: {0}")]
     InvalidLanguageId(String),
 
     #[error("invalid path: {0}")]
     InvalidPath(String),
 }
+
+/// The messages of the errors are `dioxus-i18n-error-` followed by the kebab-case name of the
+/// variant, e.g. `dioxus-i18n-error-message-id-not-found = No translation for { $key }`, with
+/// the arguments:
+///
+/// - `key` for the message and attribute ids,
+/// - `namespace`, `language`, `path` and `reason` where the variant has them,
+/// - `origin` for the resource that failed to parse,
+/// - `suggestion` for the most similar message id, if any,
+/// - `errors` for the nested errors, one per line, the configuration errors being translated.
+impl LocalizedError for Error {
+    fn try_localize_error(&self, i18n: &I18n) -> Result<String, Error> {
+        let mut args = FluentArgs::new();
+        let id = match self {
+            Self::InvalidMessageId(key) => {
+                args.set("key", key);
+                "dioxus-i18n-error-invalid-message-id"
+            }
+            Self::MessageIdNotFound { key, suggestion } => {
+                args.set("key", key);
+                args.set("suggestion", suggestion.as_deref());
+                "dioxus-i18n-error-message-id-not-found"
+            }
+            Self::AttributeIdNotFound(key) => {
+                args.set("key", key);
+                "dioxus-i18n-error-attribute-id-not-found"
+            }
+            Self::NamespaceNotFound(namespace) => {
+                args.set("namespace", namespace);
+                "dioxus-i18n-error-namespace-not-found"
+            }
+            Self::MessagePatternNotFound(key) => {
+                args.set("key", key);
+                "dioxus-i18n-error-message-pattern-not-found"
+            }
+            Self::FluentErrorsDetected {
+                key,
+                language,
+                errors,
+            } => {
+                args.set("key", key);
+                args.set("language", language.to_string());
+                args.set("errors", list(errors));
+                "dioxus-i18n-error-fluent-errors-detected"
+            }
+            Self::ResourceParseFailed {
+                language,
+                origin,
+                errors,
+            } => {
+                args.set("language", language.to_string());
+                args.set("origin", origin.to_string());
+                args.set("errors", list(errors));
+                "dioxus-i18n-error-resource-parse-failed"
+            }
+            Self::InvalidOverride {
+                key,
+                language,
+                reason,
+            } => {
+                args.set("key", key);
+                args.set("language", language.to_string());
+                args.set("reason", reason);
+                "dioxus-i18n-error-invalid-override"
+            }
+            Self::LocaleResourcePathReadFailed { path, reason } => {
+                args.set("path", path.display().to_string());
+                args.set("reason", reason);
+                "dioxus-i18n-error-locale-resource-path-read-failed"
+            }
+            Self::FallbackMustHaveLocale(language) => {
+                args.set("language", language);
+                "dioxus-i18n-error-fallback-must-have-locale"
+            }
+            Self::LanguageMustHaveLocale(language) => {
+                args.set("language", language);
+                "dioxus-i18n-error-language-must-have-locale"
+            }
+            Self::LocaleAlreadyExists(language) => {
+                args.set("language", language);
+                "dioxus-i18n-error-locale-already-exists"
+            }
+            Self::LocaleNotFound(language) => {
+                args.set("language", language);
+                "dioxus-i18n-error-locale-not-found"
+            }
+            Self::FallbackCannotBeRemoved(language) => {
+                args.set("language", language);
+                "dioxus-i18n-error-fallback-cannot-be-removed"
+            }
+            Self::InvalidConfig(errors) => {
+                let errors: Vec<_> = errors
+                    .iter()
+                    .map(|error| error.localize_error(i18n))
+                    .collect();
+                args.set("errors", list(&errors));
+                "dioxus-i18n-error-invalid-config"
+            }
+            Self::InvalidLanguageId(reason) => {
+                args.set("reason", reason);
+                "dioxus-i18n-error-invalid-language-id"
+            }
+            Self::InvalidPath(path) => {
+                args.set("path", path);
+                "dioxus-i18n-error-invalid-path"
+            }
+        };
+        i18n.try_translate_without_policy(id, Some(&args))
+    }
+}
+
+/// Where a locale resource comes from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ResourceOrigin {
+    Static,
+    Path(PathBuf),
+}
+
+impl fmt::Display for ResourceOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Static => f.write_str("static resource"),
+            Self::Path(path) => write!(f, "resource '{}'", path.display()),
+        }
+    }
+}
+
+/// A syntax error in a locale resource, with its (1-based) line and column.
+#[derive(Clone, Debug, PartialEq, Error)]
+#[error("line {line}, column {column}: {error}")]
+pub struct ResourceParseError {
+    pub line: usize,
+    pub column: usize,
+    pub error: ParserError,
+    /// The junk entry skipped by the parser, i.e. the text up to the next valid entry.
+    pub junk: Option<String>,
+}
+
+impl ResourceParseError {
+    pub(crate) fn new(source: &str, error: ParserError) -> Self {
+        let before = &source[..error.pos.start.min(source.len())];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let junk = error
+            .slice
+            .clone()
+            .and_then(|slice| source.get(slice))
+            .map(|junk| junk.trim_end().to_string());
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            error,
+            junk,
+        }
+    }
+}
+
+fn did_you_mean(suggestion: &Option<String>) -> String {
+    suggestion
+        .as_ref()
+        .map(|suggestion| format!(" (did you mean '{}'?)", suggestion))
+        .unwrap_or_default()
+}
+
+fn list(errors: &[impl fmt::Display]) -> String {
+    errors
+        .iter()
+        .map(|error| format!("\n  {}", error))
+        .collect()
+}
@@ -0,0 +1,23 @@
+use fluent::FluentArgs;
+
+/// A type which can be passed as the arguments of a translation, e.g. with
+/// `t!("greeting", args: &user)`.
+///
+/// With the `derive` feature, it is implemented with `#[derive(FluentArgs)]`:
+///
+/// ```rust,ignore
+/// #[derive(FluentArgs)]
+/// #[fluent(rename_all = "kebab-case")]
+/// struct User {
+///     name: String,
+///     item_count: usize,
+///     #[fluent(skip)]
+///     password_hash: String,
+/// }
+///
+/// // `cart = { $name } has { $item-count } items.`
+/// t!("cart", args: &user);
+/// ```
+pub trait ToFluentArgs {
+    fn to_fluent_args(&self) -> FluentArgs<'_>;
+}
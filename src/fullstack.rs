@@ -0,0 +1,216 @@
+//! Dioxus fullstack integration.
+//!
+//! The language is negotiated on the server, rendered, and then serialized into the hydration
+//! data so that the client starts with the same language (avoiding a flash of the wrong one).
+//!
+//! The client's selected language is then kept in the [`LANGUAGE_COOKIE`] cookie, which the
+//! browser sends with each server function call, so that server functions can translate into
+//! the caller's language with `request_translator`.
+//!
+//! Desktop and mobile clients have no cookie jar, so they must send the language in the
+//! [`LANGUAGE_HEADER`] header instead, e.g. with a server function client adding
+//! [`language_header`]:
+//!
+//! ```rust,ignore
+//! use server_fn::client::{reqwest::ReqwestClient, Client};
+//!
+//! pub struct I18nClient;
+//!
+//! impl<E> Client<E> for I18nClient {
+//!     type Request = reqwest::Request;
+//!     type Response = reqwest::Response;
+//!
+//!     fn send(
+//!         mut request: Self::Request,
+//!     ) -> impl Future<Output = Result<Self::Response, ServerFnError<E>>> + Send {
+//!         if let Some((name, value)) = language_header() {
+//!             request.headers_mut().insert(name, value.parse().unwrap());
+//!         }
+//!         <ReqwestClient as Client<E>>::send(request)
+//!     }
+//! }
+//!
+//! #[server(client = I18nClient)]
+//! async fn save(name: String) -> Result<String, ServerFnError> { ... }
+//! ```
+//!
+//! ```rust,ignore
+//! fn app() -> Element {
+//!     let i18n = use_init_i18n_hydrated(|| {
+//!         let config = I18nConfig::new(langid!("en-US"))
+//!             .with_locale((langid!("en-US"), include_str!("./en-US.ftl")))
+//!             .with_locale((langid!("es-ES"), include_str!("./es-ES.ftl")));
+//!
+//!         #[cfg(feature = "server")]
+//!         let config = config.with_negotiated_language(&request_languages());
+//!
+//!         config
+//!     });
+//!
+//!     rsx! { HreflangLinks { href: |id| format!("https://example.com/{id}/") } }
+//! }
+//!
+//! #[server]
+//! async fn save(name: String) -> Result<String, ServerFnError> {
+//!     let translator = request_translator(i18n_config)?;
+//!     Ok(translator.translate("saved"))
+//! }
+//! ```
+
+use crate::negotiate::{LANGUAGE_COOKIE, LANGUAGE_HEADER};
+use crate::use_i18n::{use_init_i18n, I18n, I18nConfig};
+#[cfg(feature = "server")]
+use crate::{error::Error, translator::Translator};
+
+use dioxus_fullstack::prelude::use_server_cached;
+use dioxus_lib::document;
+use dioxus_lib::prelude::*;
+use unic_langid::LanguageIdentifier;
+
+#[cfg(feature = "server")]
+use std::{any::TypeId, collections::HashMap};
+use std::{cell::RefCell, rc::Rc, sync::Mutex};
+
+/// The language selected on the client, see [`language_header`].
+static CLIENT_LANGUAGE: Mutex<Option<LanguageIdentifier>> = Mutex::new(None);
+
+/// Initialize an i18n provider whose initial language is hydrated from the server.
+///
+/// On the server the initial language of the config is used (typically chosen with
+/// [`I18nConfig::with_negotiated_language`]) and serialized into the hydration data.
+/// On the client the hydrated language overrides the initial language of the config, and
+/// the selected language is stored in the [`LANGUAGE_COOKIE`] cookie, and returned by
+/// [`language_header`], whenever it changes.
+pub fn use_init_i18n_hydrated(init: impl FnOnce() -> I18nConfig) -> I18n {
+    let config = use_hook(|| Rc::new(RefCell::new(Some(init()))));
+
+    let language = use_server_cached(|| {
+        config
+            .borrow()
+            .as_ref()
+            .map(|config| config.id.to_string())
+            .unwrap_or_default()
+    });
+
+    let i18n = use_init_i18n(|| {
+        let mut config = config
+            .take()
+            .expect("i18n config is only taken on initialisation");
+        if let Ok(id) = LanguageIdentifier::from_bytes(language.as_bytes()) {
+            config.id = id;
+        }
+        config
+    });
+
+    use_effect(move || {
+        let language = i18n.language();
+        let script = format!(
+            "document.cookie = '{}={}; path=/; max-age=31536000; SameSite=Lax'",
+            LANGUAGE_COOKIE, language
+        );
+        document::eval(&script);
+        *CLIENT_LANGUAGE.lock().unwrap_or_else(|e| e.into_inner()) = Some(language);
+    });
+
+    i18n
+}
+
+/// The [`LANGUAGE_HEADER`] header name and value for the language selected on the client with
+/// [`use_init_i18n_hydrated`], to be added to server function requests by clients without
+/// cookies, such as desktop and mobile apps. `None` before the language is known.
+pub fn language_header() -> Option<(&'static str, String)> {
+    CLIENT_LANGUAGE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .map(|language| (LANGUAGE_HEADER, language.to_string()))
+}
+
+/// The languages requested by the current request, in order of preference.
+///
+/// The language given by the [`LANGUAGE_HEADER`] header is preferred, then
+/// the language stored in the [`LANGUAGE_COOKIE`] cookie, followed by the languages in the
+/// `Accept-Language` header. Languages given by the URL
+/// should be prepended by the caller, see [`crate::negotiate::language_from_path`].
+///
+/// Must be called from within a server render or server function.
+#[cfg(feature = "server")]
+pub fn request_languages() -> Vec<LanguageIdentifier> {
+    use crate::negotiate::{language_from_cookie, parse_accept_language};
+    use dioxus_fullstack::prelude::server_context;
+
+    let context = server_context();
+    let request = context.request_parts();
+    let header = |name| {
+        request
+            .headers
+            .get_all(name)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .collect::<Vec<_>>()
+    };
+
+    let explicit = header(LANGUAGE_HEADER)
+        .into_iter()
+        .find_map(|value| LanguageIdentifier::from_bytes(value.trim().as_bytes()).ok());
+    let cookie = header("cookie").into_iter().find_map(language_from_cookie);
+    let accepted = header("accept-language")
+        .into_iter()
+        .flat_map(parse_accept_language);
+
+    explicit.into_iter().chain(cookie).chain(accepted).collect()
+}
+
+/// Get a [`Translator`] for the language of the current request.
+///
+/// `init` should create the same config as the one given to [`use_init_i18n_hydrated`]; its
+/// initial language is negotiated against [`request_languages`], so the translator uses the
+/// caller's selected language when it has a locale.
+///
+/// Translators are cached by `init` and language, for each server thread, so the resources are
+/// only read and parsed once. `init` must therefore always create the same config, e.g. be a
+/// function rather than a closure capturing the config.
+///
+/// Must be called from within a server render or server function.
+#[cfg(feature = "server")]
+pub fn request_translator<F>(init: F) -> Result<Rc<Translator>, Error>
+where
+    F: Fn() -> I18nConfig + 'static,
+{
+    use crate::negotiate::negotiate_language;
+
+    /// The languages of a config, and its translators by language.
+    struct Translators {
+        initial: LanguageIdentifier,
+        available: Vec<LanguageIdentifier>,
+        by_language: HashMap<LanguageIdentifier, Rc<Translator>>,
+    }
+
+    thread_local! {
+        static TRANSLATORS: RefCell<HashMap<TypeId, Translators>> = RefCell::default();
+    }
+
+    TRANSLATORS.with_borrow_mut(|translators| {
+        let translators = translators.entry(TypeId::of::<F>()).or_insert_with(|| {
+            let config = init();
+            Translators {
+                initial: config.id.clone(),
+                available: config.locales.keys().cloned().collect(),
+                by_language: HashMap::new(),
+            }
+        });
+
+        let language = negotiate_language(&request_languages(), &translators.available)
+            .unwrap_or_else(|| translators.initial.clone());
+        if let Some(translator) = translators.by_language.get(&language) {
+            return Ok(translator.clone());
+        }
+
+        let translator = Rc::new(Translator::try_new(I18nConfig {
+            id: language.clone(),
+            ..init()
+        })?);
+        translators.by_language.insert(language, translator.clone());
+        Ok(translator)
+    })
+}
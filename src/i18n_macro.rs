@@ -26,8 +26,19 @@
 /// # }
 /// ```
 ///
+/// The arguments can also be given as a [`crate::prelude::ToFluentArgs`], e.g. a struct
+/// deriving `FluentArgs` (`derive` feature), with `te!("hello", args: &user)`. This form is
+/// accepted by all translation macros.
+///
 #[macro_export]
 macro_rules! te {
+    ($id:expr, args: $args:expr ) => {{
+        dioxus_i18n::prelude::i18n().try_translate_with_args(
+            $id,
+            Some(&dioxus_i18n::prelude::ToFluentArgs::to_fluent_args($args)),
+        )
+    }};
+
     ($id:expr, $( $name:ident : $value:expr ),* ) => {
         {
             let mut params_map = dioxus_i18n::fluent::FluentArgs::new();
@@ -63,6 +74,10 @@ macro_rules! te {
 ///
 #[macro_export]
 macro_rules! t {
+    ($id:expr, args: $args:expr ) => {
+        dioxus_i18n::te!($id, args: $args).unwrap_or_else(|e| panic!("{}", e.to_string()))
+    };
+
     ($id:expr, $( $name:ident : $value:expr ),* ) => {
         dioxus_i18n::te!($id, $( $name : $value ),*).unwrap_or_else(|e| panic!("{}", e.to_string()))
     };
@@ -91,6 +106,10 @@ macro_rules! t {
 ///
 #[macro_export]
 macro_rules! tid {
+    ($id:expr, args: $args:expr ) => {
+        dioxus_i18n::te!($id, args: $args).unwrap_or_else(|e| e.to_string())
+    };
+
     ($id:expr, $( $name:ident : $value:expr ),* ) => {
         dioxus_i18n::te!($id, $( $name : $value ),*).unwrap_or_else(|e| e.to_string())
     };
@@ -99,3 +118,130 @@ macro_rules! tid {
         dioxus_i18n::te!($id).unwrap_or_else(|e| e.to_string())
     }};
 }
+
+/// Translate message from key into an explicit language, returning [`crate::prelude::DioxusI18nError`] if id not found...
+///
+/// The selected language is left unchanged, see [`crate::prelude::I18n::try_translate_in`].
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_i18n::{te_in, prelude::*};
+/// # use unic_langid::langid;
+/// # #[component]
+/// # fn Example() -> Element {
+/// #   let lang = langid!("en-US");
+/// #   let config = I18nConfig::new(lang.clone()).with_locale((lang.clone(), "hello = Hello, {$name}")).with_locale((langid!("es-ES"), "hello = Hola, {$name}"));
+/// #   let mut i18n = use_init_i18n(|| config);
+/// let name = "Avery Gigglesworth";
+/// let hi = te_in!(&langid!("es-ES"), "hello", name: {name}).expect("message id 'name' should be present");
+/// assert_eq!(hi, "Hola, Avery Gigglesworth");
+/// #   rsx! { "" }
+/// # }
+/// ```
+///
+#[macro_export]
+macro_rules! te_in {
+    ($lang:expr, $id:expr, args: $args:expr ) => {{
+        dioxus_i18n::prelude::i18n().try_translate_in(
+            $lang,
+            $id,
+            Some(&dioxus_i18n::prelude::ToFluentArgs::to_fluent_args($args)),
+        )
+    }};
+
+    ($lang:expr, $id:expr, $( $name:ident : $value:expr ),* ) => {
+        {
+            let mut params_map = dioxus_i18n::fluent::FluentArgs::new();
+            $(
+                params_map.set(stringify!($name), $value);
+            )*
+            dioxus_i18n::prelude::i18n().try_translate_in($lang, $id, Some(&params_map))
+        }
+    };
+
+    ($lang:expr, $id:expr ) => {{
+            dioxus_i18n::prelude::i18n().try_translate_in($lang, $id, None)
+    }};
+}
+
+/// Translate message from key into an explicit language, panic! if id not found...
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_i18n::{t_in, prelude::*};
+/// # use unic_langid::langid;
+/// # #[component]
+/// # fn Example() -> Element {
+/// #   let lang = langid!("en-US");
+/// #   let config = I18nConfig::new(lang.clone()).with_locale((lang.clone(), "hello = Hello, {$name}")).with_locale((langid!("es-ES"), "hello = Hola, {$name}"));
+/// #   let mut i18n = use_init_i18n(|| config);
+/// let name = "Avery Gigglesworth";
+/// let hi = t_in!(&langid!("es-ES"), "hello", name: {name});
+/// assert_eq!(hi, "Hola, Avery Gigglesworth");
+/// #   rsx! { "" }
+/// # }
+/// ```
+///
+#[macro_export]
+macro_rules! t_in {
+    ($lang:expr, $id:expr, args: $args:expr ) => {
+        dioxus_i18n::te_in!($lang, $id, args: $args).unwrap_or_else(|e| panic!("{}", e.to_string()))
+    };
+
+    ($lang:expr, $id:expr, $( $name:ident : $value:expr ),* ) => {
+        dioxus_i18n::te_in!($lang, $id, $( $name : $value ),*).unwrap_or_else(|e| panic!("{}", e.to_string()))
+    };
+
+    ($lang:expr, $id:expr ) => {{
+        dioxus_i18n::te_in!($lang, $id).unwrap_or_else(|e| panic!("{}", e.to_string()))
+    }};
+}
+
+/// Translate all attributes of a message into element attributes, panic! if id not found...
+///
+/// The attributes can be spread onto an element, see [`crate::prelude::element_attributes`].
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_i18n::{ta, prelude::*};
+/// # use unic_langid::langid;
+/// # #[component]
+/// # fn Example() -> Element {
+/// #   let lang = langid!("en-US");
+/// #   let config = I18nConfig::new(lang.clone()).with_locale((lang.clone(), "email = Email\n    .placeholder = {$domain} email\n    .title = Your email"));
+/// #   let mut i18n = use_init_i18n(|| config);
+/// rsx! {
+///     input { ..ta!("email", domain: "Work") }
+/// }
+/// # }
+/// ```
+///
+#[macro_export]
+macro_rules! ta {
+    ($id:expr, args: $args:expr ) => {{
+        dioxus_i18n::prelude::element_attributes(
+            dioxus_i18n::prelude::i18n().translate_attributes(
+                $id,
+                Some(&dioxus_i18n::prelude::ToFluentArgs::to_fluent_args($args)),
+            )
+        )
+    }};
+
+    ($id:expr, $( $name:ident : $value:expr ),* ) => {
+        {
+            let mut params_map = dioxus_i18n::fluent::FluentArgs::new();
+            $(
+                params_map.set(stringify!($name), $value);
+            )*
+            dioxus_i18n::prelude::element_attributes(
+                dioxus_i18n::prelude::i18n().translate_attributes($id, Some(&params_map))
+            )
+        }
+    };
+
+    ($id:expr ) => {{
+        dioxus_i18n::prelude::element_attributes(
+            dioxus_i18n::prelude::i18n().translate_attributes($id, None)
+        )
+    }};
+}
@@ -1,12 +1,42 @@
 #![doc = include_str!("../README.md")]
+mod display_names;
+#[cfg(feature = "html")]
+mod document;
 mod error;
+mod fluent_args;
+#[cfg(feature = "fullstack")]
+mod fullstack;
 pub mod i18n_macro;
+mod localize;
+mod missing_translation;
+pub mod negotiate;
+mod overrides;
+#[cfg(feature = "html")]
+mod picker;
+#[cfg(feature = "router")]
+mod router;
+mod translator;
 pub mod use_i18n;
 
 pub use fluent;
 pub use unic_langid;
 
 pub mod prelude {
-    pub use crate::error::Error as DioxusI18nError;
+    pub use crate::display_names::*;
+    #[cfg(feature = "html")]
+    pub use crate::document::*;
+    pub use crate::error::{Error as DioxusI18nError, ResourceOrigin, ResourceParseError};
+    pub use crate::fluent_args::*;
+    #[cfg(feature = "fullstack")]
+    pub use crate::fullstack::*;
+    pub use crate::localize::*;
+    pub use crate::missing_translation::*;
+    #[cfg(feature = "html")]
+    pub use crate::picker::*;
+    #[cfg(feature = "router")]
+    pub use crate::router::*;
+    pub use crate::translator::*;
     pub use crate::use_i18n::*;
+    #[cfg(feature = "derive")]
+    pub use dioxus_i18n_macros::{FluentArgs, Localize, LocalizedError};
 }
@@ -0,0 +1,75 @@
+use super::error::Error;
+use super::use_i18n::{i18n, I18n};
+
+use std::fmt::Display;
+
+/// A value which can be translated, such as a status enum or a unit.
+///
+/// With the `derive` feature, it is implemented with `#[derive(Localize)]`, which maps each
+/// variant to a message id and passes its fields as arguments:
+///
+/// ```rust,ignore
+/// #[derive(Localize)]
+/// enum OrderStatus {
+///     // `order-status-pending = Pending`
+///     Pending,
+///     // `order-status-shipped = Shipped on { $date }`
+///     Shipped { date: String },
+///     #[localize(id = "order-cancelled")]
+///     Cancelled,
+/// }
+///
+/// rsx! { "{status.localized()}" }
+/// ```
+pub trait Localize {
+    fn try_localize(&self, i18n: &I18n) -> Result<String, Error>;
+
+    fn localize(&self, i18n: &I18n) -> String {
+        let result = self.try_localize(i18n);
+        match result {
+            Ok(translation) => translation,
+            Err(err) => panic!("Failed to localize: {}", err),
+        }
+    }
+
+    /// Translate with the `I18n` of the current component's context.
+    fn localized(&self) -> String {
+        self.localize(&i18n())
+    }
+}
+
+/// An error whose message can be translated, falling back to its [`Display`] implementation
+/// when the translation fails, e.g. when its message is missing.
+///
+/// The translation is looked up with [`I18n::try_translate_without_policy`], so that the
+/// [`crate::prelude::MissingTranslationPolicy`] does not replace that fallback.
+///
+/// With the `derive` feature, it is implemented with `#[derive(LocalizedError)]`, which maps
+/// each variant to a message id as with `#[derive(Localize)]`:
+///
+/// ```rust,ignore
+/// #[derive(Debug, thiserror::Error, LocalizedError)]
+/// enum LoginError {
+///     // `login-error-invalid-password = Wrong password for { $user }`
+///     #[error("wrong password for {user}")]
+///     InvalidPassword { user: String },
+///     // Fields marked with `#[source]` or `#[from]` are not passed as arguments.
+///     #[error("network error")]
+///     Network(#[from] std::io::Error),
+/// }
+///
+/// rsx! { "{error.localized_error()}" }
+/// ```
+pub trait LocalizedError: Display {
+    fn try_localize_error(&self, i18n: &I18n) -> Result<String, Error>;
+
+    fn localize_error(&self, i18n: &I18n) -> String {
+        self.try_localize_error(i18n)
+            .unwrap_or_else(|_| self.to_string())
+    }
+
+    /// Translate with the `I18n` of the current component's context.
+    fn localized_error(&self) -> String {
+        self.localize_error(&i18n())
+    }
+}
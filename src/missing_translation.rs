@@ -0,0 +1,174 @@
+use super::error::Error;
+
+use unic_langid::LanguageIdentifier;
+
+use std::{collections::HashMap, fmt, rc::Rc};
+
+/// What lookups return when a message, attribute or namespace is missing, set with
+/// [`crate::prelude::I18nConfig::with_missing_translation_policy`].
+///
+/// The policy applies to all lookups, including the `t!`, `te!`, `tid!` and `ta!` macros, so
+/// with [`MissingTranslationPolicy::HumanizedId`], [`MissingTranslationPolicy::Marker`] or
+/// [`MissingTranslationPolicy::Callback`], `t!` does not panic on missing translations, nor
+/// does it with [`MissingTranslationPolicy::PanicInDebug`] wrapping one of them in release
+/// builds. Other errors, such as an invalid message id, are always returned.
+///
+/// When translating all attributes of a missing message, the policies returning text return
+/// no attributes, the callback being called nonetheless.
+#[derive(Clone, Default)]
+pub enum MissingTranslationPolicy {
+    /// Return the error (default).
+    #[default]
+    Error,
+    /// Panic in debug builds, and apply the inner policy in release builds.
+    PanicInDebug(Box<MissingTranslationPolicy>),
+    /// Return the id as text, e.g. "Welcome back" for `welcome-back`.
+    HumanizedId,
+    /// Return the translation in the base language, or the error if it is missing there too.
+    BaseLanguage(LanguageIdentifier),
+    /// Return the id between visible markers, e.g. "⟦welcome-back⟧".
+    Marker,
+    /// Return the result of the callback, called with the id and the error.
+    Callback(MissingTranslationCallback),
+}
+
+/// Called with the id and the error of a missing translation, see
+/// [`MissingTranslationPolicy::Callback`].
+pub type MissingTranslationCallback = Rc<dyn Fn(&str, &Error) -> String>;
+
+impl fmt::Debug for MissingTranslationPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error => f.write_str("Error"),
+            Self::PanicInDebug(policy) => f.debug_tuple("PanicInDebug").field(policy).finish(),
+            Self::HumanizedId => f.write_str("HumanizedId"),
+            Self::BaseLanguage(id) => f.debug_tuple("BaseLanguage").field(id).finish(),
+            Self::Marker => f.write_str("Marker"),
+            Self::Callback(_) => f.write_str("Callback"),
+        }
+    }
+}
+
+#[cfg(test)]
+impl PartialEq for MissingTranslationPolicy {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::PanicInDebug(a), Self::PanicInDebug(b)) => a == b,
+            (Self::BaseLanguage(a), Self::BaseLanguage(b)) => a == b,
+            (Self::Callback(a), Self::Callback(b)) => Rc::ptr_eq(a, b),
+            (a, b) => std::mem::discriminant(a) == std::mem::discriminant(b),
+        }
+    }
+}
+
+impl MissingTranslationPolicy {
+    /// Apply the policy to the `result` of translating `msg`, translating into the base
+    /// language with `translate_in`.
+    pub(crate) fn apply(
+        &self,
+        msg: &str,
+        result: Result<String, Error>,
+        translate_in: impl FnOnce(&LanguageIdentifier) -> Result<String, Error>,
+    ) -> Result<String, Error> {
+        match result {
+            Err(err) if is_missing_translation(&err) => {
+                self.apply_to_error(msg, err, translate_in, |text| text)
+            }
+            result => result,
+        }
+    }
+
+    /// Apply the policy to the `result` of translating the attributes of `msg`, see
+    /// [`MissingTranslationPolicy::apply`].
+    pub(crate) fn apply_to_attributes(
+        &self,
+        msg: &str,
+        result: Result<HashMap<String, String>, Error>,
+        translate_in: impl FnOnce(&LanguageIdentifier) -> Result<HashMap<String, String>, Error>,
+    ) -> Result<HashMap<String, String>, Error> {
+        match result {
+            Err(err) if is_missing_translation(&err) => {
+                self.apply_to_error(msg, err, translate_in, |_| HashMap::new())
+            }
+            result => result,
+        }
+    }
+
+    /// Apply the policy to the missing translation `err`, where `from_text` converts the text
+    /// returned by the policy into a translation.
+    fn apply_to_error<T>(
+        &self,
+        msg: &str,
+        err: Error,
+        translate_in: impl FnOnce(&LanguageIdentifier) -> Result<T, Error>,
+        from_text: impl FnOnce(String) -> T,
+    ) -> Result<T, Error> {
+        match self {
+            Self::Error => Err(err),
+            Self::PanicInDebug(_) if cfg!(debug_assertions) => {
+                panic!("Missing translation for {}: {}", msg, err)
+            }
+            Self::PanicInDebug(policy) => policy.apply_to_error(msg, err, translate_in, from_text),
+            Self::HumanizedId => Ok(from_text(humanize_id(msg))),
+            Self::BaseLanguage(id) => translate_in(id).map_err(|_| err),
+            Self::Marker => Ok(from_text(format!("⟦{}⟧", msg))),
+            Self::Callback(callback) => Ok(from_text(callback(msg, &err))),
+        }
+    }
+}
+
+fn is_missing_translation(err: &Error) -> bool {
+    matches!(
+        err,
+        Error::MessageIdNotFound { .. }
+            | Error::AttributeIdNotFound(_)
+            | Error::MessagePatternNotFound(_)
+            | Error::NamespaceNotFound(_)
+    )
+}
+
+/// `msg` as text: without namespace, with separators as spaces and the first letter in
+/// upper case, e.g. "Settings profile title" for `app:settings-profile.title`.
+fn humanize_id(msg: &str) -> String {
+    let id = msg.split_once(':').map_or(msg, |(_, id)| id);
+    let text = id
+        .trim_start_matches('-')
+        .replace(['-', '_', '.'], " ")
+        .trim()
+        .to_string();
+
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn humanize_id_separators() {
+        assert_eq!(humanize_id("welcome-back"), "Welcome back");
+        assert_eq!(
+            humanize_id("app:settings-profile.title"),
+            "Settings profile title"
+        );
+        assert_eq!(humanize_id("-brand_name"), "Brand name");
+    }
+
+    #[test]
+    fn apply_ignores_other_errors() {
+        let result = MissingTranslationPolicy::HumanizedId.apply(
+            "a..b",
+            Err(Error::InvalidMessageId("a..b".to_string())),
+            |_| unreachable!(),
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "invalid message id: 'a..b'"
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! Language negotiation helpers.
+//!
+//! These are primarily intended for server-side rendering, where the language is determined
+//! from the incoming request (`Accept-Language` header, cookie or URL segment) rather than
+//! hard-coded in [`crate::prelude::I18nConfig::new`].
+
+use unic_langid::LanguageIdentifier;
+
+/// The cookie name used to remember the selected language between requests.
+pub const LANGUAGE_COOKIE: &str = "dioxus-i18n-language";
+
+/// The request header used by clients to send the selected language explicitly, e.g. from
+/// desktop or mobile clients where the [LANGUAGE_COOKIE] is not available.
+pub const LANGUAGE_HEADER: &str = "x-dioxus-i18n-language";
+
+/// Parse an `Accept-Language` header value into a list of [LanguageIdentifier]s,
+/// ordered by descending quality.
+///
+/// Wildcards and entries that are not valid language identifiers are ignored.
+///
+/// ```rust
+/// # use dioxus_i18n::negotiate::parse_accept_language;
+/// # use unic_langid::langid;
+/// let requested = parse_accept_language("fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5");
+/// assert_eq!(requested, vec![langid!("fr-CH"), langid!("fr"), langid!("en")]);
+/// ```
+pub fn parse_accept_language(header: &str) -> Vec<LanguageIdentifier> {
+    let mut weighted: Vec<(LanguageIdentifier, f32)> = header
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';').map(str::trim);
+            let tag = parts.next().filter(|tag| !tag.is_empty() && *tag != "*")?;
+            let quality = parts
+                .find_map(|param| param.strip_prefix("q="))
+                .map_or(Some(1.0), |q| q.parse::<f32>().ok())?;
+            let id = LanguageIdentifier::from_bytes(tag.as_bytes()).ok()?;
+            (quality > 0.0).then_some((id, quality))
+        })
+        .collect();
+
+    // `sort_by` is stable, so equal qualities keep their header order.
+    weighted.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    weighted.into_iter().map(|(id, _)| id).collect()
+}
+
+/// Extract the language from a `Cookie` header value, using the [LANGUAGE_COOKIE] name.
+pub fn language_from_cookie(header: &str) -> Option<LanguageIdentifier> {
+    header
+        .split(';')
+        .filter_map(|cookie| cookie.trim().split_once('='))
+        .find(|(name, _)| *name == LANGUAGE_COOKIE)
+        .and_then(|(_, value)| LanguageIdentifier::from_bytes(value.trim().as_bytes()).ok())
+}
+
+/// Extract the language from the first segment of a URL path, e.g. `/es-ES/settings`.
+///
+/// Only segments matching one of the `available` languages are accepted, because many
+/// ordinary path segments (e.g. `about`) are syntactically valid language identifiers. The
+/// matching available language is returned (see [`negotiate_language`]), e.g. `es-ES` for
+/// `/es/settings` if only `es-ES` is available.
+pub fn language_from_path<'a>(
+    path: &str,
+    available: impl IntoIterator<Item = &'a LanguageIdentifier>,
+) -> Option<LanguageIdentifier> {
+    let segment = path.trim_start_matches('/').split('/').next()?;
+    let id = LanguageIdentifier::from_bytes(segment.as_bytes()).ok()?;
+    negotiate_language(std::slice::from_ref(&id), available)
+}
+
+/// Choose the best `available` language for the `requested` languages.
+///
+/// The requested languages are considered in order. For each, an exact match is preferred,
+/// then the most specific available language it falls back to (following the same
+/// _<language>-<optionalScript>-<optionalRegion>-<optionalVariants>_ hierarchy used when
+/// creating the translation bundle), and finally any available language sharing the
+/// primary language subtag.
+///
+/// ```rust
+/// # use dioxus_i18n::negotiate::negotiate_language;
+/// # use unic_langid::langid;
+/// let available = [langid!("en"), langid!("es-ES")];
+/// let requested = [langid!("de"), langid!("es"), langid!("en")];
+/// assert_eq!(negotiate_language(&requested, &available), Some(langid!("es-ES")));
+/// ```
+pub fn negotiate_language<'a>(
+    requested: &[LanguageIdentifier],
+    available: impl IntoIterator<Item = &'a LanguageIdentifier>,
+) -> Option<LanguageIdentifier> {
+    let mut available: Vec<&LanguageIdentifier> = available.into_iter().collect();
+    available.sort_by_key(|id| id.to_string());
+
+    requested.iter().find_map(|requested| {
+        let exact = available.iter().find(|a| **a == requested);
+
+        let fallback = || {
+            available
+                .iter()
+                .filter(|a| a.matches(requested, true, false))
+                .max_by_key(|a| specificity(a))
+        };
+
+        let related = || available.iter().find(|a| a.language == requested.language);
+
+        exact
+            .or_else(fallback)
+            .or_else(related)
+            .map(|a| (*a).clone())
+    })
+}
+
+fn specificity(id: &LanguageIdentifier) -> usize {
+    usize::from(id.script.is_some()) + usize::from(id.region.is_some()) + id.variants().len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use unic_langid::langid;
+
+    #[test]
+    fn accept_language_is_ordered_by_quality() {
+        let requested = parse_accept_language("en;q=0.5, de-CH;q=0.9, fr, es;q=0");
+        assert_eq!(
+            requested,
+            vec![langid!("fr"), langid!("de-CH"), langid!("en")]
+        );
+    }
+
+    #[test]
+    fn accept_language_ignores_invalid_entries() {
+        let requested = parse_accept_language("*, not_a_language, en-GB;q=abc, it");
+        assert_eq!(requested, vec![langid!("it")]);
+    }
+
+    #[test]
+    fn language_can_be_read_from_cookie() {
+        let cookie = format!("theme=dark; {}=es-ES; other=1", LANGUAGE_COOKIE);
+        assert_eq!(language_from_cookie(&cookie), Some(langid!("es-ES")));
+        assert_eq!(language_from_cookie("theme=dark"), None);
+    }
+
+    #[test]
+    fn language_can_be_read_from_path() {
+        let available = [langid!("en"), langid!("es")];
+        assert_eq!(
+            language_from_path("/es/settings", &available),
+            Some(langid!("es"))
+        );
+        assert_eq!(language_from_path("/about", &available), None);
+    }
+
+    #[test]
+    fn language_from_path_negotiates_less_specific_segment() {
+        let available = [langid!("en"), langid!("es-ES")];
+        assert_eq!(
+            language_from_path("/es/settings", &available),
+            Some(langid!("es-ES"))
+        );
+    }
+
+    #[test]
+    fn negotiation_prefers_exact_match() {
+        let available = [langid!("en"), langid!("en-GB"), langid!("en-US")];
+        assert_eq!(
+            negotiate_language(&[langid!("en-GB")], &available),
+            Some(langid!("en-GB"))
+        );
+    }
+
+    #[test]
+    fn negotiation_falls_back_along_subtags() {
+        let available = [langid!("la"), langid!("la-Scpt"), langid!("fb-FB")];
+        assert_eq!(
+            negotiate_language(&[langid!("la-Scpt-LA")], &available),
+            Some(langid!("la-Scpt"))
+        );
+    }
+
+    #[test]
+    fn negotiation_uses_related_language() {
+        let available = [langid!("fb-FB"), langid!("la-Scpt-LA")];
+        assert_eq!(
+            negotiate_language(&[langid!("la")], &available),
+            Some(langid!("la-Scpt-LA"))
+        );
+    }
+
+    #[test]
+    fn negotiation_fails_without_any_match() {
+        let available = [langid!("en")];
+        assert_eq!(negotiate_language(&[langid!("de")], &available), None);
+    }
+}
@@ -0,0 +1,259 @@
+use super::error::{Error, ResourceParseError};
+use super::use_i18n::{ActiveBundle, Entry, I18n};
+
+use fluent::FluentResource;
+use fluent_syntax::{ast, serializer};
+use unic_langid::LanguageIdentifier;
+
+use std::collections::{BTreeMap, HashMap};
+
+/// The overridden messages of a language, by message id.
+pub(crate) type LanguageOverrides = BTreeMap<String, MessageOverride>;
+
+/// The overrides of a namespace (or of the locales outside of namespaces), by language.
+pub(crate) type NamespaceOverrides = HashMap<LanguageIdentifier, LanguageOverrides>;
+
+/// Messages set at runtime with [`crate::prelude::I18n::try_set_override`], which take
+/// precedence over the resources of their language.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Overrides(HashMap<Option<String>, NamespaceOverrides>);
+
+/// The overridden value and attributes of a message or term, as Fluent patterns.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct MessageOverride {
+    value: Option<String>,
+    attributes: BTreeMap<String, String>,
+}
+
+/// The message which a pattern is parsed as, see [`parse_pattern`].
+const PATTERN_PREFIX: &str = "override = ";
+
+impl Overrides {
+    /// The overrides of `namespace`, or of the locales outside of namespaces if `None`.
+    pub(crate) fn namespace(&self, namespace: Option<&str>) -> Option<&NamespaceOverrides> {
+        self.0.get(&namespace.map(str::to_string))
+    }
+
+    /// Override `msg` in `language` with `pattern`, which must be a valid Fluent pattern.
+    pub(crate) fn try_set(
+        &mut self,
+        language: &LanguageIdentifier,
+        msg: &str,
+        pattern: &str,
+    ) -> Result<(), Error> {
+        let key = I18n::decompose_identifier(msg)?;
+        parse_pattern(pattern).map_err(|reason| Error::InvalidOverride {
+            key: msg.to_string(),
+            language: language.clone(),
+            reason,
+        })?;
+
+        let message = self
+            .0
+            .entry(key.namespace.map(str::to_string))
+            .or_default()
+            .entry(language.clone())
+            .or_default()
+            .entry(key.message_id.to_string())
+            .or_default();
+        match key.attribute_name {
+            Some(attribute_name) => {
+                message
+                    .attributes
+                    .insert(attribute_name.to_string(), pattern.to_string());
+            }
+            None => message.value = Some(pattern.to_string()),
+        }
+        Ok(())
+    }
+
+    /// Remove the override of `msg` in `language`, returning whether there was one.
+    pub(crate) fn remove(&mut self, language: &LanguageIdentifier, msg: &str) -> bool {
+        let Ok(key) = I18n::decompose_identifier(msg) else {
+            return false;
+        };
+        let Some(messages) = self
+            .0
+            .get_mut(&key.namespace.map(str::to_string))
+            .and_then(|languages| languages.get_mut(language))
+        else {
+            return false;
+        };
+        let Some(message) = messages.get_mut(key.message_id.as_ref()) else {
+            return false;
+        };
+
+        let removed = match key.attribute_name {
+            Some(attribute_name) => message.attributes.remove(attribute_name).is_some(),
+            None => message.value.take().is_some(),
+        };
+        if message.value.is_none() && message.attributes.is_empty() {
+            messages.remove(key.message_id.as_ref());
+        }
+        removed
+    }
+
+    /// The overrides of `language` in `namespace` as a Fluent resource.
+    pub(crate) fn to_ftl(&self, language: &LanguageIdentifier, namespace: Option<&str>) -> String {
+        self.namespace(namespace)
+            .and_then(|languages| languages.get(language))
+            .map(|messages| serialize(None, messages))
+            .unwrap_or_default()
+    }
+}
+
+/// The resource overriding the entries of `active` with `messages`, keeping the value and
+/// attributes which are not overridden.
+pub(crate) fn override_resource(
+    active: &ActiveBundle,
+    messages: &LanguageOverrides,
+) -> FluentResource {
+    // Overrides are validated when they are set, so no entries are dropped as junk.
+    FluentResource::try_new(serialize(Some(active), messages))
+        .unwrap_or_else(|(partial, _)| partial)
+}
+
+/// Serialize `messages` as a Fluent resource, merged with the entries of `active`, if any.
+fn serialize(active: Option<&ActiveBundle>, messages: &LanguageOverrides) -> String {
+    // The patterns are parsed first, as the entries below borrow from them.
+    let parsed: Vec<_> = messages
+        .iter()
+        .map(|(id, message)| {
+            let value = message.value.as_deref().and_then(|p| parse_pattern(p).ok());
+            let attributes: Vec<_> = message
+                .attributes
+                .iter()
+                .filter_map(|(id, p)| Some((id.as_str(), parse_pattern(p).ok()?)))
+                .collect();
+            (id.as_str(), value, attributes)
+        })
+        .collect();
+
+    let body = parsed
+        .iter()
+        .map(|(id, value, attributes)| {
+            let existing = active.and_then(|active| active.get_entry(id));
+            let value = value
+                .as_ref()
+                .and_then(pattern)
+                .or_else(|| existing.as_ref().and_then(Entry::value));
+            let overridden = |name: &str| {
+                attributes
+                    .iter()
+                    .find(|(id, _)| *id == name)
+                    .and_then(|(_, resource)| pattern(resource))
+            };
+
+            // Existing attributes keep their position, followed by the new ones.
+            let existing_attributes = existing.as_ref().map(Entry::attributes).unwrap_or_default();
+            let mut merged: Vec<_> = existing_attributes
+                .iter()
+                .map(|(name, value)| attribute(name, overridden(name).unwrap_or(value)))
+                .collect();
+            merged.extend(attributes.iter().filter_map(|(name, resource)| {
+                let is_new = !existing_attributes.iter().any(|(id, _)| id == name);
+                is_new.then(|| Some(attribute(name, pattern(resource)?)))?
+            }));
+
+            match id.strip_prefix('-') {
+                Some(name) => ast::Entry::Term(ast::Term {
+                    id: ast::Identifier { name },
+                    value: value.cloned().unwrap_or(ast::Pattern { elements: vec![] }),
+                    attributes: merged,
+                    comment: None,
+                }),
+                None => ast::Entry::Message(ast::Message {
+                    id: ast::Identifier { name: *id },
+                    value: value.cloned(),
+                    attributes: merged,
+                    comment: None,
+                }),
+            }
+        })
+        .collect();
+    serializer::serialize(&ast::Resource { body })
+}
+
+fn attribute<'s>(name: &'s str, value: &ast::Pattern<&'s str>) -> ast::Attribute<&'s str> {
+    ast::Attribute {
+        id: ast::Identifier { name },
+        value: value.clone(),
+    }
+}
+
+/// Parse `pattern` as the value of a message, failing with the reason if it is not exactly
+/// one valid pattern.
+fn parse_pattern(pattern: &str) -> Result<FluentResource, String> {
+    let source = format!("{}{}", PATTERN_PREFIX, pattern.replace('\n', "\n    "));
+    let resource = FluentResource::try_new(source).map_err(|(partial, errors)| {
+        errors
+            .into_iter()
+            .map(|error| {
+                let mut error = ResourceParseError::new(partial.source(), error);
+                // Report the position within `pattern`, without the prefix and indentation.
+                error.column = match error.line {
+                    1 => error.column.saturating_sub(PATTERN_PREFIX.len()),
+                    _ => error.column.saturating_sub(4),
+                }
+                .max(1);
+                error.to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    })?;
+
+    match resource.entries().collect::<Vec<_>>().as_slice() {
+        [ast::Entry::Message(message)] if message.attributes.is_empty() => Ok(resource),
+        _ => Err("not a single pattern".to_string()),
+    }
+}
+
+fn pattern(resource: &FluentResource) -> Option<&ast::Pattern<&str>> {
+    match resource.get_entry(0) {
+        Some(ast::Entry::Message(message)) => message.value.as_ref(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use unic_langid::langid;
+
+    #[test]
+    fn invalid_pattern_is_rejected() {
+        assert_eq!(
+            parse_pattern("Hello {").err(),
+            Some("line 1, column 8: Expected an inline expression".to_string())
+        );
+        assert_eq!(
+            parse_pattern("Hello\n.title = Title").err(),
+            Some("not a single pattern".to_string())
+        );
+        assert!(parse_pattern("Hello, { $name }!\nWelcome back").is_ok());
+    }
+
+    #[test]
+    fn overrides_are_exported_as_ftl() {
+        let en = langid!("en");
+        let mut overrides = Overrides::default();
+        for (msg, pattern) in [
+            ("hello", "Hi, { $name }!"),
+            ("login.placeholder", "Your email"),
+            ("-brand", "Acme"),
+        ] {
+            overrides.try_set(&en, msg, pattern).unwrap();
+        }
+        assert_eq!(
+            overrides.to_ftl(&en, None),
+            "-brand = Acme\nhello = Hi, { $name }!\nlogin =\n    .placeholder = Your email\n"
+        );
+
+        assert!(overrides.remove(&en, "login.placeholder"));
+        assert!(!overrides.remove(&en, "login.placeholder"));
+        assert_eq!(
+            overrides.to_ftl(&en, None),
+            "-brand = Acme\nhello = Hi, { $name }!\n"
+        );
+    }
+}
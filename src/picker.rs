@@ -0,0 +1,63 @@
+//! A ready-made language picker (requires the `html` feature).
+
+use crate::display_names::language_label;
+use crate::use_i18n::i18n;
+
+use dioxus_lib::prelude::*;
+use unic_langid::LanguageIdentifier;
+
+/// A `select` of the available languages, which changes the selected language.
+///
+/// Each language is shown in its own language (see [`language_label`]), sorted by label.
+///
+/// ```rust,ignore
+/// rsx! {
+///     LanguagePicker { class: "language-picker" }
+///     LanguagePicker { label: |id: LanguageIdentifier| id.to_string() }
+/// }
+/// ```
+#[component]
+pub fn LanguagePicker(
+    /// The label of each language, [`language_label`] by default.
+    label: Option<Callback<LanguageIdentifier, String>>,
+    /// The class attribute for the `option` tags.
+    option_class: Option<String>,
+    #[props(extends = GlobalAttributes, extends = select)] attributes: Vec<Attribute>,
+) -> Element {
+    let mut i18n = i18n();
+    let selected = i18n.language();
+
+    let mut options: Vec<_> = i18n
+        .available_languages()
+        .into_iter()
+        .map(|id| {
+            let text = match label {
+                Some(label) => label(id.clone()),
+                None => language_label(&id),
+            };
+            (id, text)
+        })
+        .collect();
+    options.sort_by_cached_key(|(_, text)| text.to_lowercase());
+
+    rsx! {
+        select {
+            onchange: move |evt| {
+                if let Ok(id) = LanguageIdentifier::from_bytes(evt.value().as_bytes()) {
+                    i18n.set_language(id);
+                }
+            },
+            ..attributes,
+            for (id, text) in options {
+                option {
+                    key: "{id}",
+                    class: option_class.clone(),
+                    value: id.to_string(),
+                    lang: id.to_string(),
+                    selected: id == selected,
+                    {text}
+                }
+            }
+        }
+    }
+}
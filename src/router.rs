@@ -0,0 +1,208 @@
+//! Locale-prefixed routes with `dioxus-router` (requires the `router` feature).
+//!
+//! The first segment of the url is the source of truth for the language, e.g. `/es/settings`
+//! and `/en/settings`. A layout component nested under the locale segment keeps the `I18n`
+//! language in sync with the route:
+//!
+//! ```rust,ignore
+//! #[derive(Routable, Clone, PartialEq)]
+//! enum Route {
+//!     #[nest("/:lang")]
+//!         #[layout(LocalizedLayout)]
+//!             #[route("/settings")]
+//!             Settings { lang: String },
+//! }
+//!
+//! #[component]
+//! fn LocalizedLayout(lang: String) -> Element {
+//!     use_route_language(lang);
+//!     rsx! {
+//!         LocalizedLink { to: "/settings", "Settings" }
+//!         button { onclick: |_| switch_route_language(langid!("es")), "Español" }
+//!         Outlet::<Route> {}
+//!     }
+//! }
+//! ```
+
+use crate::negotiate::language_from_path;
+use crate::use_i18n::i18n;
+
+use dioxus_lib::prelude::*;
+use dioxus_router::prelude::{navigator, router, Link};
+use unic_langid::LanguageIdentifier;
+
+/// Keep the `I18n` language in sync with the locale `segment` of the current route.
+///
+/// The language is only changed when the segment matches a locale, and is then set to the
+/// matching locale's language, e.g. `es-ES` for `es` if only `es-ES` is available (see
+/// [`language_from_path`]); other segments are ignored.
+pub fn use_route_language(segment: String) {
+    let mut i18n = i18n();
+    use_effect(use_reactive!(|segment| {
+        let Some(id) = language_from_path(&segment, &i18n.available_languages()) else {
+            return;
+        };
+        if i18n.peek_language() != id {
+            i18n.set_language(id);
+        }
+    }));
+}
+
+/// Replace (or insert) the locale prefix of `route` with `id`.
+///
+/// The first segment is treated as the locale prefix only if it is one of the `available`
+/// languages.
+///
+/// ```rust
+/// # use dioxus_i18n::prelude::localize_route;
+/// # use unic_langid::langid;
+/// let available = [langid!("en"), langid!("es")];
+/// assert_eq!(localize_route("/es/settings", &langid!("en"), &available), "/en/settings");
+/// assert_eq!(localize_route("/settings", &langid!("es"), &available), "/es/settings");
+/// ```
+pub fn localize_route<'a>(
+    route: &str,
+    id: &LanguageIdentifier,
+    available: impl IntoIterator<Item = &'a LanguageIdentifier>,
+) -> String {
+    let (path, query) = split_query(route);
+    let path = path.trim_start_matches('/');
+    let unprefixed = match language_from_path(path, available) {
+        Some(_) => path.split_once('/').map_or("", |(_, rest)| rest),
+        None => path,
+    };
+
+    match unprefixed {
+        "" => format!("/{}{}", id, query),
+        rest => format!("/{}/{}{}", id, rest, query),
+    }
+}
+
+fn split_query(route: &str) -> (&str, &str) {
+    route
+        .find(['?', '#'])
+        .map_or((route, ""), |index| route.split_at(index))
+}
+
+/// Translate each segment of `route` through the message bundle, using the message id
+/// `route-<segment>`. Segments without a translation are left unchanged, regardless of the
+/// [`crate::prelude::MissingTranslationPolicy`].
+///
+/// Routes receiving translated slugs must therefore use dynamic segments.
+pub fn translate_route_slugs(route: &str) -> String {
+    let i18n = i18n();
+    map_segments(route, |segment| {
+        i18n.try_translate_without_policy(&format!("route-{}", segment), None)
+            .unwrap_or_else(|_| segment.to_string())
+    })
+}
+
+/// Translate the slugs of `route`, translated into the selected language with
+/// [`translate_route_slugs`], into language `id`.
+///
+/// Each segment which is the translation of a `route-<slug>` message is replaced by the
+/// translation of that message in `id`, or by `<slug>` if there is none.
+pub fn retranslate_route_slugs(route: &str, id: &LanguageIdentifier) -> String {
+    let i18n = i18n();
+    let slugs: Vec<(String, String)> = i18n
+        .message_ids()
+        .into_iter()
+        .filter_map(|msg| {
+            let slug = msg.strip_prefix("route-")?;
+            let translation = i18n.try_translate_without_policy(&msg, None).ok()?;
+            Some((translation, slug.to_string()))
+        })
+        .collect();
+
+    map_segments(route, |segment| {
+        match slugs.iter().find(|(translation, _)| translation == segment) {
+            Some((_, slug)) => i18n
+                .try_translate_in_without_policy(id, &format!("route-{}", slug), None)
+                .unwrap_or_else(|_| slug.clone()),
+            None => segment.to_string(),
+        }
+    })
+}
+
+/// Replace each non-empty segment of the path of `route` with `f(segment)`.
+fn map_segments(route: &str, f: impl Fn(&str) -> String) -> String {
+    let (path, query) = split_query(route);
+    let path = path
+        .split('/')
+        .map(|segment| match segment {
+            "" => segment.to_string(),
+            _ => f(segment),
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+
+    format!("{}{}", path, query)
+}
+
+/// Navigate to the current route in language `id`, translating its slugs into `id` (see
+/// [`translate_route_slugs`]).
+///
+/// The language itself is changed by [`use_route_language`] when the new route is rendered.
+pub fn switch_route_language(id: LanguageIdentifier) {
+    let route = retranslate_route_slugs(&router().full_route_string(), &id);
+    navigator().push(localize_route(&route, &id, &i18n().available_languages()));
+}
+
+/// A [`Link`] to `to` in the currently selected language.
+///
+/// The link is rewritten whenever the language changes.
+#[component]
+pub fn LocalizedLink(
+    /// The route, without locale prefix, e.g. `/settings`.
+    to: String,
+    /// Translate the route's segments, see [`translate_route_slugs`].
+    #[props(default)]
+    translate_slugs: bool,
+    /// The class attribute for the `a` tag.
+    class: Option<String>,
+    /// A class to apply to the `a` tag if the route is active.
+    active_class: Option<String>,
+    children: Element,
+) -> Element {
+    let i18n = i18n();
+    let to = match translate_slugs {
+        true => translate_route_slugs(&to),
+        false => to,
+    };
+    let to = localize_route(&to, &i18n.language(), &i18n.available_languages());
+
+    rsx! {
+        Link { to, class, active_class, {children} }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use unic_langid::langid;
+
+    #[test]
+    fn localize_route_replaces_locale_prefix() {
+        let available = [langid!("en"), langid!("es-ES")];
+        assert_eq!(
+            localize_route("/es-ES/settings/profile", &langid!("en"), &available),
+            "/en/settings/profile"
+        );
+        assert_eq!(localize_route("/es-ES", &langid!("en"), &available), "/en");
+        assert_eq!(
+            localize_route("/es-ES?tab=1", &langid!("en"), &available),
+            "/en?tab=1"
+        );
+    }
+
+    #[test]
+    fn localize_route_inserts_locale_prefix() {
+        let available = [langid!("en"), langid!("es-ES")];
+        assert_eq!(
+            localize_route("/about", &langid!("es-ES"), &available),
+            "/es-ES/about"
+        );
+        assert_eq!(localize_route("/", &langid!("es-ES"), &available), "/es-ES");
+    }
+}
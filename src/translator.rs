@@ -0,0 +1,159 @@
+use super::error::Error;
+use super::missing_translation::MissingTranslationPolicy;
+use super::use_i18n::{
+    try_create_bundle, try_create_namespace_bundle, try_translate_from_bundle, ActiveBundle, I18n,
+    I18nConfig, LanguageBundles, LocaleResource,
+};
+
+use fluent::FluentArgs;
+use std::{cell::RefCell, collections::HashMap};
+use unic_langid::LanguageIdentifier;
+
+/// A `Translator` provides translations for a fixed language without a Dioxus runtime.
+///
+/// It is built from the same [`I18nConfig`] as the `I18n` context, and is intended for code
+/// running outside of components, such as server functions producing localized error
+/// messages or emails (see `request_translator` with the `server` feature).
+///
+/// A `Translator` is not `Send`, so it should not be held across an `.await`.
+pub struct Translator {
+    language: LanguageIdentifier,
+    fallback: Option<LanguageIdentifier>,
+    locale_resources: Vec<LocaleResource>,
+    locales: HashMap<LanguageIdentifier, usize>,
+    namespaces: HashMap<String, HashMap<LanguageIdentifier, usize>>,
+    missing_translation_policy: MissingTranslationPolicy,
+    lenient_parsing: bool,
+    active_bundle: ActiveBundle,
+    /// Bundles for namespaces, and for the base language of the missing translation policy.
+    bundles: RefCell<LanguageBundles>,
+}
+
+impl Translator {
+    /// Create a translator for the initial language of the config.
+    pub fn try_new(config: I18nConfig) -> Result<Self, Error> {
+        if config.strict_validation {
+            config.validate()?;
+        }
+
+        let I18nConfig {
+            id,
+            fallback,
+            locale_resources,
+            locales,
+            namespaces,
+            missing_translation_policy,
+            strict_validation: _,
+            lenient_parsing,
+        } = config;
+
+        let active_bundle = try_create_bundle(
+            &id,
+            &fallback,
+            &locale_resources,
+            &locales,
+            lenient_parsing,
+            None,
+        )?;
+        Ok(Self {
+            language: id,
+            fallback,
+            locale_resources,
+            locales,
+            namespaces,
+            missing_translation_policy,
+            lenient_parsing,
+            active_bundle,
+            bundles: RefCell::new(HashMap::new()),
+        })
+    }
+
+    /// Create a translator for the initial language of the config.
+    pub fn new(config: I18nConfig) -> Self {
+        match Self::try_new(config) {
+            Ok(translator) => translator,
+            Err(err) => panic!("Translator cannot be created: {}", err),
+        }
+    }
+
+    /// The language of the translations.
+    pub fn language(&self) -> &LanguageIdentifier {
+        &self.language
+    }
+
+    /// The syntax errors of the resources for the language whose valid entries were kept with
+    /// [`I18nConfig::with_lenient_parsing`], as [`Error::ResourceParseFailed`].
+    pub fn resource_diagnostics(&self) -> &[Error] {
+        &self.active_bundle.diagnostics
+    }
+
+    pub fn try_translate_with_args(
+        &self,
+        msg: &str,
+        args: Option<&FluentArgs>,
+    ) -> Result<String, Error> {
+        let result = self.try_translate_in_bundle(&self.language, msg, args);
+        self.missing_translation_policy.apply(msg, result, |id| {
+            self.try_translate_in_bundle(id, msg, args)
+        })
+    }
+
+    fn try_translate_in_bundle(
+        &self,
+        id: &LanguageIdentifier,
+        msg: &str,
+        args: Option<&FluentArgs>,
+    ) -> Result<String, Error> {
+        let key = I18n::decompose_identifier(msg)?;
+        if *id == self.language && key.namespace.is_none() {
+            return try_translate_from_bundle(&self.active_bundle, msg, args);
+        }
+
+        let cache_key = (id.clone(), key.namespace.map(str::to_string));
+        let mut bundles = self.bundles.borrow_mut();
+        if !bundles.contains_key(&cache_key) {
+            let bundle = match key.namespace {
+                Some(namespace) => try_create_namespace_bundle(
+                    namespace,
+                    id,
+                    &self.fallback,
+                    &self.locale_resources,
+                    &self.namespaces,
+                    self.lenient_parsing,
+                    None,
+                ),
+                None => try_create_bundle(
+                    id,
+                    &self.fallback,
+                    &self.locale_resources,
+                    &self.locales,
+                    self.lenient_parsing,
+                    None,
+                ),
+            }?;
+            bundles.insert(cache_key.clone(), bundle);
+        }
+        try_translate_from_bundle(&bundles[&cache_key], msg, args)
+    }
+
+    pub fn translate_with_args(&self, msg: &str, args: Option<&FluentArgs>) -> String {
+        let result = self.try_translate_with_args(msg, args);
+        match result {
+            Ok(translation) => translation,
+            Err(err) => panic!("Failed to translate {}: {}", msg, err),
+        }
+    }
+
+    #[inline]
+    pub fn try_translate(&self, msg: &str) -> Result<String, Error> {
+        self.try_translate_with_args(msg, None)
+    }
+
+    pub fn translate(&self, msg: &str) -> String {
+        let result = self.try_translate(msg);
+        match result {
+            Ok(translation) => translation,
+            Err(err) => panic!("Failed to translate {}: {}", msg, err),
+        }
+    }
+}
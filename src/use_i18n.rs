@@ -1,13 +1,22 @@
-use super::error::Error;
+use super::error::{Error, ResourceOrigin, ResourceParseError};
+use super::missing_translation::MissingTranslationPolicy;
+use super::negotiate::negotiate_language;
+use super::overrides::{override_resource, NamespaceOverrides, Overrides};
 
 use dioxus_lib::prelude::*;
-use fluent::{FluentArgs, FluentBundle, FluentResource};
+use fluent::{FluentArgs, FluentBundle, FluentError, FluentMessage, FluentResource, FluentValue};
+use fluent_syntax::ast;
 use unic_langid::LanguageIdentifier;
 
 #[cfg(not(target_arch = "wasm32"))]
 use walkdir::WalkDir;
 
-use std::collections::HashMap;
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
 
 #[cfg(not(target_arch = "wasm32"))]
 use std::path::{Path, PathBuf};
@@ -47,7 +56,7 @@ where
 }
 
 /// A `LocaleResource` can be static text, or a filesystem file (not supported in WASM).
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum LocaleResource {
     Static(&'static str),
     #[cfg(not(target_arch = "wasm32"))]
@@ -59,8 +68,21 @@ impl LocaleResource {
         match self {
             Self::Static(str) => Ok(str.to_string()),
             #[cfg(not(target_arch = "wasm32"))]
-            Self::Path(path) => std::fs::read_to_string(path)
-                .map_err(|e| Error::LocaleResourcePathReadFailed(e.to_string())),
+            Self::Path(path) => {
+                std::fs::read_to_string(path).map_err(|e| Error::LocaleResourcePathReadFailed {
+                    path: path.clone(),
+                    reason: e.to_string(),
+                })
+            }
+        }
+    }
+
+    /// Where the resource comes from, for error reporting.
+    pub fn origin(&self) -> ResourceOrigin {
+        match self {
+            Self::Static(_) => ResourceOrigin::Static,
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::Path(path) => ResourceOrigin::Path(path.clone()),
         }
     }
 
@@ -90,17 +112,29 @@ impl From<PathBuf> for LocaleResource {
 #[cfg_attr(test, derive(Debug, PartialEq))]
 pub struct I18nConfig {
     /// The initial language, can be later changed with [`I18n::set_language`]
-    id: LanguageIdentifier,
+    pub(crate) id: LanguageIdentifier,
 
     /// The final fallback language if no other locales are found for `id`.
     /// A `Locale` must exist in `locales' if `fallback` is defined.
-    fallback: Option<LanguageIdentifier>,
+    pub(crate) fallback: Option<LanguageIdentifier>,
 
     /// The locale_resources added to the configuration.
-    locale_resources: Vec<LocaleResource>,
+    pub(crate) locale_resources: Vec<LocaleResource>,
 
     /// The locales added to the configuration.
-    locales: HashMap<LanguageIdentifier, usize>,
+    pub(crate) locales: HashMap<LanguageIdentifier, usize>,
+
+    /// The namespaced locales added to the configuration, by namespace.
+    pub(crate) namespaces: HashMap<String, HashMap<LanguageIdentifier, usize>>,
+
+    /// What lookups return when a translation is missing.
+    pub(crate) missing_translation_policy: MissingTranslationPolicy,
+
+    /// Validate the configuration when creating `I18n`, see [`I18nConfig::validate`].
+    pub(crate) strict_validation: bool,
+
+    /// Keep the valid entries of resources with syntax errors, see [`I18nConfig::with_lenient_parsing`].
+    pub(crate) lenient_parsing: bool,
 }
 
 impl I18nConfig {
@@ -111,15 +145,136 @@ impl I18nConfig {
             fallback: None,
             locale_resources: Vec::new(),
             locales: HashMap::new(),
+            namespaces: HashMap::new(),
+            missing_translation_policy: MissingTranslationPolicy::default(),
+            strict_validation: false,
+            lenient_parsing: false,
         }
     }
 
+    /// Select the initial language by negotiating the `requested` languages (in order of
+    /// preference) against the locales added to the configuration.
+    ///
+    /// The initial language is left unchanged if none of the requested languages match.
+    /// Locales should therefore be added _before_ calling this method.
+    ///
+    /// This is intended for server-side rendering, where the requested languages are taken from
+    /// the request, e.g. with [`crate::negotiate::parse_accept_language`].
+    pub fn with_negotiated_language(mut self, requested: &[LanguageIdentifier]) -> Self {
+        if let Some(id) = negotiate_language(requested, self.locales.keys()) {
+            self.id = id;
+        }
+        self
+    }
+
     /// Set a fallback [LanguageIdentifier].
     pub fn with_fallback(mut self, fallback: LanguageIdentifier) -> Self {
         self.fallback = Some(fallback);
         self
     }
 
+    /// Set the [MissingTranslationPolicy], e.g. to never panic in release builds:
+    ///
+    /// ```rust
+    /// # use dioxus_i18n::prelude::*;
+    /// # use unic_langid::langid;
+    /// let config = I18nConfig::new(langid!("es-ES")).with_missing_translation_policy(
+    ///     MissingTranslationPolicy::PanicInDebug(Box::new(
+    ///         MissingTranslationPolicy::BaseLanguage(langid!("en")),
+    ///     )),
+    /// );
+    /// ```
+    pub fn with_missing_translation_policy(mut self, policy: MissingTranslationPolicy) -> Self {
+        self.missing_translation_policy = policy;
+        self
+    }
+
+    /// Validate the whole configuration when `I18n` (or a `Translator`) is created, failing
+    /// with all problems found by [`I18nConfig::validate`], rather than only parsing the
+    /// resources of the initial language.
+    pub fn with_strict_validation(mut self) -> Self {
+        self.strict_validation = true;
+        self
+    }
+
+    /// Keep the valid messages and terms of a resource with syntax errors, rather than failing
+    /// to load its language.
+    ///
+    /// The invalid (junk) entries are skipped, and their errors are available from
+    /// [`I18n::resource_diagnostics`]. A resource that cannot be read is still an error.
+    ///
+    /// With [`I18nConfig::with_strict_validation`], syntax errors are then not validation
+    /// failures; they are reported by [`I18nConfig::resource_diagnostics`].
+    pub fn with_lenient_parsing(mut self) -> Self {
+        self.lenient_parsing = true;
+        self
+    }
+
+    /// Read and parse every resource, including namespaced ones, and check that the initial
+    /// and fallback languages have locales.
+    ///
+    /// All problems are reported at once, as [`Error::InvalidConfig`]. With
+    /// [`I18nConfig::with_lenient_parsing`], syntax errors are not problems, as the valid entries
+    /// of the resources are kept; see [`I18nConfig::resource_diagnostics`].
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut errors = vec![];
+
+        if !resolution_chain(&self.id)
+            .iter()
+            .any(|langid| self.locales.contains_key(langid))
+        {
+            errors.push(Error::LanguageMustHaveLocale(self.id.to_string()));
+        }
+        if let Some(fallback) = &self.fallback {
+            if !self.locales.contains_key(fallback) {
+                errors.push(Error::FallbackMustHaveLocale(fallback.to_string()));
+            }
+        }
+
+        for result in self.parse_resources() {
+            match result {
+                Ok(Some(_)) if self.lenient_parsing => (),
+                Ok(Some(error)) | Err(error) => errors.push(error),
+                Ok(None) => (),
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(Error::InvalidConfig(errors)),
+        }
+    }
+
+    /// Read and parse every resource, including namespaced ones, returning the syntax errors as
+    /// [`Error::ResourceParseFailed`], e.g. to report the entries skipped with
+    /// [`I18nConfig::with_lenient_parsing`]. Resources that cannot be read are ignored, see
+    /// [`I18nConfig::validate`].
+    pub fn resource_diagnostics(&self) -> Vec<Error> {
+        self.parse_resources()
+            .into_iter()
+            .filter_map(|result| result.ok().flatten())
+            .collect()
+    }
+
+    /// Read and parse each resource once, returning its syntax error if any.
+    fn parse_resources(&self) -> Vec<Result<Option<Error>, Error>> {
+        let mut locales: Vec<_> = self
+            .locales
+            .iter()
+            .chain(self.namespaces.values().flatten())
+            .map(|(langid, &index)| (index, langid))
+            .collect();
+        locales.sort_by_cached_key(|(index, langid)| (*index, langid.to_string()));
+        locales.dedup_by_key(|(index, _)| *index);
+
+        locales
+            .into_iter()
+            .map(|(index, langid)| {
+                parse_resource(langid, &self.locale_resources[index]).map(|(_, error)| error)
+            })
+            .collect()
+    }
+
     /// Add [Locale].
     /// It is possible to share locales resources. If this locale's resource
     /// matches a previously added one, then this locale will use the existing one.
@@ -129,20 +284,43 @@ impl I18nConfig {
         T: Into<Locale>,
     {
         let locale = locale.into();
+        let index = self.add_locale_resource(locale.resource);
+        self.locales.insert(locale.id, index);
+        self
+    }
+
+    /// Add [Locale] to the named `namespace`.
+    ///
+    /// Namespaced messages are addressed as `namespace:message-id[.attribute-id]`, e.g.
+    /// `t!("settings:title")`. A namespace's resources are only read and parsed the first time
+    /// one of its messages is translated, and follow the same fallback rules as other locales.
+    pub fn with_namespaced_locale<T>(mut self, namespace: &str, locale: T) -> Self
+    where
+        T: Into<Locale>,
+    {
+        let locale = locale.into();
+        let index = self.add_locale_resource(locale.resource);
+        self.namespaces
+            .entry(namespace.to_string())
+            .or_default()
+            .insert(locale.id, index);
+        self
+    }
+
+    fn add_locale_resource(&mut self, resource: LocaleResource) -> usize {
         let locale_resources_len = self.locale_resources.len();
 
         let index = self
             .locale_resources
             .iter()
-            .position(|r| *r == locale.resource)
+            .position(|r| *r == resource)
             .unwrap_or(locale_resources_len);
 
         if index == locale_resources_len {
-            self.locale_resources.push(locale.resource)
+            self.locale_resources.push(resource)
         };
 
-        self.locales.insert(locale.id, index);
-        self
+        index
     }
 
     /// Add multiple locales from given folder, based on their filename.
@@ -227,14 +405,7 @@ fn is_ftl_file(entry: &Path) -> bool {
 pub fn try_use_init_i18n(init: impl FnOnce() -> I18nConfig) -> Result<I18n, Error> {
     use_context_provider(move || {
         // Coverage false -ve: See https://github.com/xd009642/tarpaulin/issues/1675
-        let I18nConfig {
-            id,
-            fallback,
-            locale_resources,
-            locales,
-        } = init();
-
-        I18n::try_new(id, fallback, locale_resources, locales)
+        I18n::try_from_config(init())
     })
 }
 
@@ -242,29 +413,115 @@ pub fn try_use_init_i18n(init: impl FnOnce() -> I18nConfig) -> Result<I18n, Erro
 pub fn use_init_i18n(init: impl FnOnce() -> I18nConfig) -> I18n {
     use_context_provider(move || {
         // Coverage false -ve: See https://github.com/xd009642/tarpaulin/issues/1675
-        let I18nConfig {
-            id,
-            fallback,
-            locale_resources,
-            locales,
-        } = init();
-
-        match I18n::try_new(id, fallback, locale_resources, locales) {
+        match I18n::try_from_config(init()) {
             Ok(i18n) => i18n,
             Err(e) => panic!("Failed to create I18n context: {}", e),
         }
     })
 }
 
+/// Provide a child `I18n` for the current component's subtree, with its own selected language.
+///
+/// The scope shares the locales of the nearest `I18n`, so no resources are duplicated, and
+/// `t!` and friends, in the rest of the calling component and in its descendant components,
+/// translate into `id`. This is useful for e.g. previewing a document in another language.
+/// Changing the scope's language leaves the parent unchanged.
+///
+/// Locales and overrides are shared too: adding, replacing or removing a locale, or setting an
+/// override, through either the parent or a scope reloads the translations of both.
+///
+/// The scope's language follows `id` when it changes between renders.
+pub fn use_i18n_scope(id: LanguageIdentifier) -> I18n {
+    let parent = i18n();
+    let scope = use_context_provider(|| match parent.try_scope(id.clone()) {
+        Ok(i18n) => i18n,
+        Err(e) => panic!("Failed to create I18n scope: {}", e),
+    });
+    use_drop(move || scope.unlink());
+
+    let mut previous = use_hook(|| CopyValue::new(id.clone()));
+    use_effect(use_reactive!(|id| {
+        if *previous.peek() != id {
+            previous.set(id.clone());
+            let mut scope = scope;
+            scope.set_language(id);
+        }
+    }));
+
+    scope
+}
+
+/// A subtree translated into `lang`, see [`use_i18n_scope`].
+///
+/// Only the components among the children pick up the scope: rsx children are evaluated in
+/// the caller's render, so `t!` written directly in them translates into the caller's language.
+///
+/// ```rust,ignore
+/// rsx! {
+///     // `DocumentPreview` is translated into Spanish.
+///     I18nScope { lang: langid!("es-ES"), DocumentPreview {} }
+///     // "Hello": `t!` is evaluated here, outside the scope.
+///     I18nScope { lang: langid!("es-ES"), p { {t!("hello")} } }
+/// }
+/// ```
+#[component]
+pub fn I18nScope(
+    /// The language for the subtree.
+    lang: LanguageIdentifier,
+    children: Element,
+) -> Element {
+    use_i18n_scope(lang);
+    rsx! { {children} }
+}
+
 #[derive(Clone, Copy)]
 pub struct I18n {
     selected_language: Signal<LanguageIdentifier>,
     fallback_language: Signal<Option<LanguageIdentifier>>,
     locale_resources: Signal<Vec<LocaleResource>>,
     locales: Signal<HashMap<LanguageIdentifier, usize>>,
-    active_bundle: Signal<FluentBundle<FluentResource>>,
+    namespaces: Signal<HashMap<String, HashMap<LanguageIdentifier, usize>>>,
+    overrides: Signal<Overrides>,
+    global_args: Signal<HashMap<String, GlobalArg>>,
+    missing_translation_policy: CopyValue<MissingTranslationPolicy>,
+    active_bundle: Signal<ActiveBundle>,
+    namespace_bundles: CopyValue<HashMap<String, ActiveBundle>>,
+    language_bundles: CopyValue<LanguageBundles>,
+    language_changed_listeners: CopyValue<LanguageChangedListeners>,
+    /// The `I18n` and its scopes sharing these locales, reloaded together when they change.
+    linked: CopyValue<Vec<I18n>>,
+    lenient_parsing: bool,
+}
+
+type LanguageChangedListener = Rc<dyn Fn(&LanguageIdentifier, &LanguageIdentifier)>;
+
+/// An argument passed to every translation, see [`I18n::set_global_arg`].
+#[derive(Clone)]
+enum GlobalArg {
+    Value(FluentValue<'static>),
+    /// Read when translating, so that translations follow changes of the signal.
+    Signal(Rc<dyn Fn() -> FluentValue<'static>>),
 }
 
+impl GlobalArg {
+    fn value(&self) -> FluentValue<'static> {
+        match self {
+            Self::Value(value) => value.clone(),
+            Self::Signal(read) => read(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct LanguageChangedListeners {
+    next_id: usize,
+    listeners: Vec<(usize, LanguageChangedListener)>,
+}
+
+/// Identifies a listener registered with [`I18n::on_language_changed`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LanguageChangedListenerId(usize);
+
 impl I18n {
     pub fn try_new(
         selected_language: LanguageIdentifier,
@@ -272,19 +529,94 @@ impl I18n {
         locale_resources: Vec<LocaleResource>,
         locales: HashMap<LanguageIdentifier, usize>,
     ) -> Result<Self, Error> {
+        Self::try_from_config(I18nConfig {
+            fallback: fallback_language,
+            locale_resources,
+            locales,
+            ..I18nConfig::new(selected_language)
+        })
+    }
+
+    pub(crate) fn try_from_config(config: I18nConfig) -> Result<Self, Error> {
+        if config.strict_validation {
+            config.validate()?;
+        }
+
+        let I18nConfig {
+            id,
+            fallback,
+            locale_resources,
+            locales,
+            namespaces,
+            missing_translation_policy,
+            strict_validation: _,
+            lenient_parsing,
+        } = config;
+
         let bundle = try_create_bundle(
-            &selected_language,
-            &fallback_language,
+            &id,
+            &fallback,
             &locale_resources,
             &locales,
+            lenient_parsing,
+            None,
         )?;
-        Ok(Self {
-            selected_language: Signal::new(selected_language),
-            fallback_language: Signal::new(fallback_language),
+        let i18n = Self {
+            selected_language: Signal::new(id),
+            fallback_language: Signal::new(fallback),
             locale_resources: Signal::new(locale_resources),
             locales: Signal::new(locales),
+            namespaces: Signal::new(namespaces),
+            overrides: Signal::new(Overrides::default()),
+            global_args: Signal::new(HashMap::new()),
+            missing_translation_policy: CopyValue::new(missing_translation_policy),
             active_bundle: Signal::new(bundle),
-        })
+            namespace_bundles: CopyValue::new(HashMap::new()),
+            language_bundles: CopyValue::new(HashMap::new()),
+            language_changed_listeners: CopyValue::new(LanguageChangedListeners::default()),
+            linked: CopyValue::new(vec![]),
+            lenient_parsing,
+        };
+        i18n.link();
+        Ok(i18n)
+    }
+
+    /// Create a child `I18n` for language `id`, sharing the locales of `self`.
+    fn try_scope(&self, id: LanguageIdentifier) -> Result<Self, Error> {
+        let fallback = self.fallback_language.peek().clone();
+        let bundle = try_create_bundle(
+            &id,
+            &fallback,
+            &self.locale_resources.peek(),
+            &self.locales.peek(),
+            self.lenient_parsing,
+            self.overrides.peek().namespace(None),
+        )?;
+        let scope = Self {
+            selected_language: Signal::new(id),
+            fallback_language: Signal::new(fallback),
+            active_bundle: Signal::new(bundle),
+            namespace_bundles: CopyValue::new(HashMap::new()),
+            language_bundles: CopyValue::new(HashMap::new()),
+            language_changed_listeners: CopyValue::new(LanguageChangedListeners::default()),
+            ..*self
+        };
+        scope.link();
+        Ok(scope)
+    }
+
+    /// Add `self` to the `I18n`s sharing its locales.
+    fn link(&self) {
+        let mut linked = self.linked;
+        linked.write().push(*self);
+    }
+
+    /// Remove `self` from the `I18n`s sharing its locales, e.g. when a scope is dropped.
+    fn unlink(&self) {
+        let mut linked = self.linked;
+        if let Ok(mut linked) = linked.try_write() {
+            linked.retain(|i18n| i18n.selected_language != self.selected_language);
+        };
     }
 
     pub fn new(
@@ -310,42 +642,304 @@ impl I18n {
         msg: &str,
         args: Option<&FluentArgs>,
     ) -> Result<String, Error> {
-        let (message_id, attribute_name) = Self::decompose_identifier(msg)?;
+        self.with_global_args(args, |args| {
+            let result = self.try_translate_from_bundles(msg, args);
+            self.apply_missing_translation_policy(msg, args, result)
+        })
+    }
+
+    /// As [`I18n::try_translate_with_args`], without applying the [`MissingTranslationPolicy`],
+    /// for lookups with their own fallback, such as route slugs and error messages (see
+    /// [`crate::prelude::LocalizedError`]).
+    pub fn try_translate_without_policy(
+        &self,
+        msg: &str,
+        args: Option<&FluentArgs>,
+    ) -> Result<String, Error> {
+        self.with_global_args(args, |args| self.try_translate_from_bundles(msg, args))
+    }
 
-        let bundle = self.active_bundle.read();
+    fn try_translate_from_bundles(
+        &self,
+        msg: &str,
+        args: Option<&FluentArgs>,
+    ) -> Result<String, Error> {
+        let key = Self::decompose_identifier(msg)?;
+        self.with_bundle(key.namespace, |active| {
+            try_translate_from_bundle(active, msg, args)
+        })
+        .and_then(|result| result)
+    }
 
-        let message = bundle
-            .get_message(message_id)
-            .ok_or_else(|| Error::MessageIdNotFound(message_id.into()))?;
+    /// Translate `msg`, returning the best-effort text together with any formatting errors,
+    /// rather than failing with [`Error::FluentErrorsDetected`].
+    ///
+    /// E.g. with a missing argument `name`, `Hello, {$name}!` is translated as
+    /// `Hello, {$name}!` with an error for the unknown variable. Missing translations are still
+    /// returned as errors, subject to the [`MissingTranslationPolicy`].
+    pub fn translate_with_diagnostics(
+        &self,
+        msg: &str,
+        args: Option<&FluentArgs>,
+    ) -> Result<Translation, Error> {
+        self.with_global_args(args, |args| {
+            let key = Self::decompose_identifier(msg)?;
+            let result = self
+                .with_bundle(key.namespace, |active| {
+                    try_format_from_bundle(active, msg, args)
+                })
+                .and_then(|result| result);
+
+            match result {
+                Ok(translation) => Ok(translation),
+                Err(err) => self
+                    .apply_missing_translation_policy(msg, args, Err(err))
+                    .map(|text| Translation {
+                        text,
+                        errors: vec![],
+                    }),
+            }
+        })
+    }
 
-        let pattern = if let Some(attribute_name) = attribute_name {
-            let attribute = message
-                .get_attribute(attribute_name)
-                .ok_or_else(|| Error::AttributeIdNotFound(msg.to_string()))?;
-            attribute.value()
-        } else {
-            message
-                .value()
-                .ok_or_else(|| Error::MessagePatternNotFound(message_id.into()))?
+    fn apply_missing_translation_policy(
+        &self,
+        msg: &str,
+        args: Option<&FluentArgs>,
+        result: Result<String, Error>,
+    ) -> Result<String, Error> {
+        self.missing_translation_policy
+            .read()
+            .apply(msg, result, |id| {
+                self.try_translate_in_bundle(id, msg, args)
+            })
+    }
+
+    /// Translate `msg` into language `id`, leaving the selected language unchanged.
+    ///
+    /// The bundle for `id` follows the same subtag and fallback rules as the selected
+    /// language's, and is cached for subsequent translations.
+    pub fn try_translate_in(
+        &self,
+        id: &LanguageIdentifier,
+        msg: &str,
+        args: Option<&FluentArgs>,
+    ) -> Result<String, Error> {
+        self.with_global_args(args, |args| {
+            let result = self.try_translate_in_bundle(id, msg, args);
+            self.apply_missing_translation_policy(msg, args, result)
+        })
+    }
+
+    /// As [`I18n::try_translate_in`], without applying the [`MissingTranslationPolicy`] (see
+    /// [`I18n::try_translate_without_policy`]).
+    #[cfg(feature = "router")]
+    pub(crate) fn try_translate_in_without_policy(
+        &self,
+        id: &LanguageIdentifier,
+        msg: &str,
+        args: Option<&FluentArgs>,
+    ) -> Result<String, Error> {
+        self.with_global_args(args, |args| self.try_translate_in_bundle(id, msg, args))
+    }
+
+    fn try_translate_in_bundle(
+        &self,
+        id: &LanguageIdentifier,
+        msg: &str,
+        args: Option<&FluentArgs>,
+    ) -> Result<String, Error> {
+        let key = Self::decompose_identifier(msg)?;
+        self.with_language_bundle(id, key.namespace, |active| {
+            try_translate_from_bundle(active, msg, args)
+        })?
+    }
+
+    /// Call `f` with the bundle for language `id` and `namespace` (or outside of namespaces if
+    /// `None`), created on first use and cached until the locales change.
+    fn with_language_bundle<R>(
+        &self,
+        id: &LanguageIdentifier,
+        namespace: Option<&str>,
+        f: impl FnOnce(&ActiveBundle) -> R,
+    ) -> Result<R, Error> {
+        let fallback_language = self.fallback_language.read();
+        let cache_key = (id.clone(), namespace.map(str::to_string));
+
+        let mut language_bundles = self.language_bundles.write_unchecked();
+        if !language_bundles.contains_key(&cache_key) {
+            let bundle = match namespace {
+                Some(namespace) => try_create_namespace_bundle(
+                    namespace,
+                    id,
+                    &fallback_language,
+                    &self.locale_resources.peek(),
+                    &self.namespaces.peek(),
+                    self.lenient_parsing,
+                    self.overrides.peek().namespace(Some(namespace)),
+                ),
+                None => try_create_bundle(
+                    id,
+                    &fallback_language,
+                    &self.locale_resources.peek(),
+                    &self.locales.peek(),
+                    self.lenient_parsing,
+                    self.overrides.peek().namespace(None),
+                ),
+            }?;
+            language_bundles.insert(cache_key.clone(), bundle);
+        }
+        Ok(f(&language_bundles[&cache_key]))
+    }
+
+    pub fn translate_in(
+        &self,
+        id: &LanguageIdentifier,
+        msg: &str,
+        args: Option<&FluentArgs>,
+    ) -> String {
+        let result = self.try_translate_in(id, msg, args);
+        match result {
+            Ok(translation) => translation,
+            Err(err) => panic!("Failed to translate {} in {}: {}", msg, id, err),
+        }
+    }
+
+    /// Set an argument passed to every translation, e.g. the product name or the user's
+    /// grammatical gender, so that it need not be passed to each `t!`. Arguments passed to a
+    /// translation take precedence over global arguments of the same name.
+    ///
+    /// ```rust,ignore
+    /// i18n.set_global_arg("product", "Acme");
+    /// // `welcome = Welcome to { $product }!`
+    /// t!("welcome");
+    /// ```
+    pub fn set_global_arg(
+        &mut self,
+        name: impl Into<String>,
+        value: impl Into<FluentValue<'static>>,
+    ) {
+        self.global_args
+            .write()
+            .insert(name.into(), GlobalArg::Value(value.into()));
+    }
+
+    /// Set an argument passed to every translation to the value of `signal`, which is read
+    /// when translating, so that translations follow changes of the signal (see
+    /// [`I18n::set_global_arg`]).
+    pub fn set_global_arg_signal<T>(
+        &mut self,
+        name: impl Into<String>,
+        signal: impl Into<ReadOnlySignal<T>>,
+    ) where
+        T: Clone + Into<FluentValue<'static>> + 'static,
+    {
+        let signal = signal.into();
+        self.global_args.write().insert(
+            name.into(),
+            GlobalArg::Signal(Rc::new(move || signal.read().clone().into())),
+        );
+    }
+
+    /// Remove an argument set with [`I18n::set_global_arg`] or [`I18n::set_global_arg_signal`].
+    pub fn remove_global_arg(&mut self, name: &str) {
+        if self.global_args.peek().contains_key(name) {
+            self.global_args.write().remove(name);
+        }
+    }
+
+    /// Call `f` with `args` merged into the global arguments, `args` taking precedence.
+    fn with_global_args<R>(
+        &self,
+        args: Option<&FluentArgs>,
+        f: impl FnOnce(Option<&FluentArgs>) -> R,
+    ) -> R {
+        // The arguments are cloned so that `f`, e.g. a missing translation callback, may set
+        // global arguments.
+        let global_args: Vec<(String, GlobalArg)> = self
+            .global_args
+            .read()
+            .iter()
+            .map(|(name, arg)| (name.clone(), arg.clone()))
+            .collect();
+        if global_args.is_empty() {
+            return f(args);
+        }
+
+        let mut merged = FluentArgs::with_capacity(global_args.len());
+        for (name, arg) in global_args {
+            merged.set(name, arg.value());
+        }
+        for (name, value) in args.into_iter().flat_map(FluentArgs::iter) {
+            merged.set(name, value.clone());
+        }
+        f(Some(&merged))
+    }
+
+    /// Decompose `msg`, in the form `[namespace:]message-id[.attribute-id]`, into its parts.
+    ///
+    /// The message id may be a term id, e.g. `-brand-name`.
+    ///
+    /// Fluent ids cannot contain `.`, so the last `.` separates the attribute id, and any other
+    /// `.` in the message id is read as `-`. E.g. `settings.profile.title` is attribute `title`
+    /// of message `settings-profile`. When translating, such a key falls back to the whole key
+    /// as a message id, i.e. message `settings-profile-title` (see [`MessageKey::flattened_id`]).
+    /// A key with a single `.`, e.g. `settings.language`, only falls back to the whole key if
+    /// its message (`settings`) does not exist.
+    pub fn decompose_identifier(msg: &str) -> Result<MessageKey<'_>, Error> {
+        let (namespace, id) = match msg.split_once(':') {
+            Some((namespace, id)) => (Some(namespace), id),
+            None => (None, msg),
         };
 
-        let mut errors = vec![];
-        let translation = bundle
-            .format_pattern(pattern, args, &mut errors)
-            .to_string();
+        let (message_id, attribute_name) = match id.rsplit_once('.') {
+            Some((message_id, attribute_name)) => (message_id, Some(attribute_name)),
+            None => (id, None),
+        };
+
+        if message_id.split('.').any(str::is_empty) || attribute_name == Some("") {
+            return Err(Error::InvalidMessageId(msg.to_string()));
+        }
 
-        (errors.is_empty())
-            .then_some(translation)
-            .ok_or_else(|| Error::FluentErrorsDetected(format!("{:#?}", errors)))
+        let message_id = match message_id.contains('.') {
+            true => Cow::Owned(message_id.replace('.', "-")),
+            false => Cow::Borrowed(message_id),
+        };
+
+        Ok(MessageKey {
+            namespace,
+            message_id,
+            attribute_name,
+        })
     }
 
-    pub fn decompose_identifier(msg: &str) -> Result<(&str, Option<&str>), Error> {
-        let parts: Vec<&str> = msg.split('.').collect();
-        match parts.as_slice() {
-            [message_id] => Ok((message_id, None)),
-            [message_id, attribute_name] => Ok((message_id, Some(attribute_name))),
-            _ => Err(Error::InvalidMessageId(msg.to_string())),
+    /// Call `f` with the bundle for `namespace`, or with the active bundle if `None`.
+    ///
+    /// Namespace bundles are created on first use, and cached until the language changes.
+    fn with_bundle<R>(
+        &self,
+        namespace: Option<&str>,
+        f: impl FnOnce(&ActiveBundle) -> R,
+    ) -> Result<R, Error> {
+        let active = self.active_bundle.read();
+        let Some(namespace) = namespace else {
+            return Ok(f(&active));
+        };
+
+        let mut namespace_bundles = self.namespace_bundles.write_unchecked();
+        if !namespace_bundles.contains_key(namespace) {
+            let bundle = try_create_namespace_bundle(
+                namespace,
+                &self.selected_language.peek(),
+                &self.fallback_language.peek(),
+                &self.locale_resources.peek(),
+                &self.namespaces.peek(),
+                self.lenient_parsing,
+                self.overrides.peek().namespace(Some(namespace)),
+            )?;
+            namespace_bundles.insert(namespace.to_string(), bundle);
         }
+        Ok(f(&namespace_bundles[namespace]))
     }
 
     pub fn translate_with_args(&self, msg: &str, args: Option<&FluentArgs>) -> String {
@@ -369,21 +963,204 @@ impl I18n {
         }
     }
 
+    /// Translate all attributes of message `msg`, in the form `[namespace:]message-id`,
+    /// returning a map of attribute id to translation.
+    ///
+    /// A missing message is subject to the [`MissingTranslationPolicy`]. See
+    /// [`element_attributes`] to spread the attributes onto an element.
+    pub fn try_translate_attributes(
+        &self,
+        msg: &str,
+        args: Option<&FluentArgs>,
+    ) -> Result<HashMap<String, String>, Error> {
+        self.with_global_args(args, |args| self.try_translate_attributes_with(msg, args))
+    }
+
+    fn try_translate_attributes_with(
+        &self,
+        msg: &str,
+        args: Option<&FluentArgs>,
+    ) -> Result<HashMap<String, String>, Error> {
+        let key = Self::decompose_identifier(msg)?;
+        let message_id = key.whole_id();
+
+        let result = self
+            .with_bundle(key.namespace, |active| {
+                translate_attributes_from_bundle(active, &message_id, args)
+            })
+            .and_then(|result| result);
+        self.missing_translation_policy
+            .read()
+            .apply_to_attributes(msg, result, |id| {
+                self.with_language_bundle(id, key.namespace, |active| {
+                    translate_attributes_from_bundle(active, &message_id, args)
+                })?
+            })
+    }
+
+    pub fn translate_attributes(
+        &self,
+        msg: &str,
+        args: Option<&FluentArgs>,
+    ) -> HashMap<String, String> {
+        let result = self.try_translate_attributes(msg, args);
+        match result {
+            Ok(translations) => translations,
+            Err(err) => panic!("Failed to translate attributes of {}: {}", msg, err),
+        }
+    }
+
     /// Get the selected language.
     #[inline]
     pub fn language(&self) -> LanguageIdentifier {
         self.selected_language.read().clone()
     }
 
+    /// Get the language of the locale providing the message (or attribute) `msg`.
+    ///
+    /// This differs from the selected language when the message is provided by a less
+    /// specific locale, or by the fallback language. `None` if the message does not exist.
+    pub fn message_language(&self, msg: &str) -> Option<LanguageIdentifier> {
+        let key = Self::decompose_identifier(msg).ok()?;
+        self.with_bundle(key.namespace, |active| {
+            active
+                .message_languages
+                .get(key.message_id.as_ref())
+                .cloned()
+        })
+        .ok()
+        .flatten()
+    }
+
+    /// Whether the message (or term) `msg`, in the form `[namespace:]message-id`, exists.
+    pub fn has_message(&self, msg: &str) -> bool {
+        let Ok(key) = Self::decompose_identifier(msg) else {
+            return false;
+        };
+        self.with_bundle(key.namespace, |active| {
+            active.get_entry(&key.whole_id()).is_some()
+        })
+        .unwrap_or(false)
+    }
+
+    /// Whether the attribute `msg`, in the form `[namespace:]message-id.attribute-id`, exists.
+    pub fn has_attribute(&self, msg: &str) -> bool {
+        let Ok(key) = Self::decompose_identifier(msg) else {
+            return false;
+        };
+        let Some(attribute_name) = key.attribute_name else {
+            return false;
+        };
+        self.with_bundle(key.namespace, |active| {
+            active
+                .get_entry(&key.message_id)
+                .is_some_and(|entry| entry.attribute(attribute_name).is_some())
+        })
+        .unwrap_or(false)
+    }
+
+    /// The ids of the messages in the selected language, including those provided by less
+    /// specific locales and the fallback language, sorted.
+    pub fn message_ids(&self) -> Vec<String> {
+        let mut ids: Vec<_> = self
+            .active_bundle
+            .read()
+            .message_languages
+            .keys()
+            .cloned()
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// As [`I18n::message_ids`], with the language of the locale providing each message (see
+    /// [`I18n::message_language`]).
+    pub fn message_ids_with_languages(&self) -> Vec<(String, LanguageIdentifier)> {
+        let mut ids: Vec<_> = self
+            .active_bundle
+            .read()
+            .message_languages
+            .iter()
+            .map(|(id, language)| (id.clone(), language.clone()))
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// The ids of the attributes of message `msg`, in the form `[namespace:]message-id`, in
+    /// their order of definition. Empty if the message does not exist.
+    pub fn attributes(&self, msg: &str) -> Vec<String> {
+        let Ok(key) = Self::decompose_identifier(msg) else {
+            return vec![];
+        };
+        self.with_bundle(key.namespace, |active| {
+            active
+                .get_entry(&key.whole_id())
+                .map(|entry| {
+                    entry
+                        .attributes()
+                        .into_iter()
+                        .map(|(id, _)| id.to_string())
+                        .collect()
+                })
+                .unwrap_or_default()
+        })
+        .unwrap_or_default()
+    }
+
+    /// The syntax errors of the resources whose valid entries were kept with
+    /// [`I18nConfig::with_lenient_parsing`], as [`Error::ResourceParseFailed`], for the selected
+    /// language and the namespaces loaded so far.
+    pub fn resource_diagnostics(&self) -> Vec<Error> {
+        let mut diagnostics = self.active_bundle.read().diagnostics.clone();
+        let namespace_bundles = self.namespace_bundles.read();
+        let mut namespaces: Vec<_> = namespace_bundles.keys().collect();
+        namespaces.sort();
+        for namespace in namespaces {
+            diagnostics.extend(namespace_bundles[namespace].diagnostics.iter().cloned());
+        }
+        diagnostics
+    }
+
+    /// Get the selected language, without subscribing to changes.
+    #[cfg(feature = "router")]
+    pub(crate) fn peek_language(&self) -> LanguageIdentifier {
+        self.selected_language.peek().clone()
+    }
+
+    /// The languages with a locale, sorted by their identifier.
+    pub fn available_languages(&self) -> Vec<LanguageIdentifier> {
+        let mut languages: Vec<_> = self.locales.read().keys().cloned().collect();
+        languages.sort_by_cached_key(|id| id.to_string());
+        languages
+    }
+
+    /// The CLDR display names of `id` in the selected language (requires the `display-names`
+    /// feature), see [`crate::prelude::display_names`].
+    #[cfg(feature = "display-names")]
+    pub fn display_names(
+        &self,
+        id: &LanguageIdentifier,
+    ) -> Option<crate::display_names::DisplayNames> {
+        crate::display_names::display_names(id, &self.selected_language.read())
+    }
+
     /// Get the fallback language.
     pub fn fallback_language(&self) -> Option<LanguageIdentifier> {
         self.fallback_language.read().clone()
     }
 
     /// Update the selected language.
+    ///
+    /// The translations are loaded before the language is changed, so that on error the
+    /// selected language and its translations are left untouched.
     pub fn try_set_language(&mut self, id: LanguageIdentifier) -> Result<(), Error> {
-        *self.selected_language.write() = id;
-        self.try_update_active_bundle()
+        let bundle = self.try_create_active_bundle(&id, &self.fallback_language.peek())?;
+
+        let previous = self.selected_language.replace(id);
+        self.set_active_bundle(bundle);
+        self.notify_language_changed(&previous);
+        Ok(())
     }
 
     /// Update the selected language.
@@ -397,14 +1174,21 @@ impl I18n {
     }
 
     /// Update the fallback language.
+    ///
+    /// As with [`I18n::try_set_language`], the fallback language is left untouched on error.
     pub fn try_set_fallback_language(&mut self, id: LanguageIdentifier) -> Result<(), Error> {
         self.locales
             .read()
             .get(&id)
             .ok_or_else(|| Error::FallbackMustHaveLocale(id.to_string()))?;
 
-        *self.fallback_language.write() = Some(id);
-        self.try_update_active_bundle()
+        let fallback = Some(id);
+        let bundle = self.try_create_active_bundle(&self.selected_language.peek(), &fallback)?;
+
+        *self.fallback_language.write() = fallback;
+        self.language_bundles.write().clear();
+        self.set_active_bundle(bundle);
+        Ok(())
     }
 
     /// Update the fallback language.
@@ -417,55 +1201,764 @@ impl I18n {
         }
     }
 
-    fn try_update_active_bundle(&mut self) -> Result<(), Error> {
-        let bundle = try_create_bundle(
-            &self.selected_language.peek(),
-            &self.fallback_language.peek(),
+    /// Add a locale for a language without one, e.g. a language pack downloaded after startup.
+    ///
+    /// The translations are only reloaded if the language is part of the selected language's
+    /// fallback chain (e.g. adding `en-GB` while `en-GB` or `en-GB-oxendict` is selected), or is
+    /// the fallback language. The same applies to the scopes sharing the locales (see
+    /// [`use_i18n_scope`]).
+    pub fn try_add_locale<T>(&mut self, locale: T) -> Result<(), Error>
+    where
+        T: Into<Locale>,
+    {
+        let locale = locale.into();
+        if self.locales.peek().contains_key(&locale.id) {
+            return Err(Error::LocaleAlreadyExists(locale.id.to_string()));
+        }
+        self.try_update_locale(locale.id, Some(locale.resource))
+    }
+
+    /// Add a locale for a language without one, see [`I18n::try_add_locale`].
+    pub fn add_locale<T>(&mut self, locale: T)
+    where
+        T: Into<Locale>,
+    {
+        let result = self.try_add_locale(locale);
+        match result {
+            Ok(()) => (),
+            Err(err) => panic!("cannot add locale: {}", err),
+        }
+    }
+
+    /// Replace the locale of a language, e.g. with corrected translations.
+    ///
+    /// Replacing a locale with the same path reloads the file. As with [`I18n::try_add_locale`],
+    /// the translations are only reloaded if the language is in use.
+    pub fn try_replace_locale<T>(&mut self, locale: T) -> Result<(), Error>
+    where
+        T: Into<Locale>,
+    {
+        let locale = locale.into();
+        if !self.locales.peek().contains_key(&locale.id) {
+            return Err(Error::LocaleNotFound(locale.id.to_string()));
+        }
+        self.try_update_locale(locale.id, Some(locale.resource))
+    }
+
+    /// Replace the locale of a language, see [`I18n::try_replace_locale`].
+    pub fn replace_locale<T>(&mut self, locale: T)
+    where
+        T: Into<Locale>,
+    {
+        let result = self.try_replace_locale(locale);
+        match result {
+            Ok(()) => (),
+            Err(err) => panic!("cannot replace locale: {}", err),
+        }
+    }
+
+    /// Remove the locale of language `id`, which must not be the fallback language.
+    ///
+    /// As with [`I18n::try_add_locale`], the translations are only reloaded if the language is
+    /// in use.
+    pub fn try_remove_locale(&mut self, id: &LanguageIdentifier) -> Result<(), Error> {
+        let linked = self.linked.peek().clone();
+        if linked
+            .iter()
+            .any(|i18n| i18n.fallback_language.peek().as_ref() == Some(id))
+        {
+            return Err(Error::FallbackCannotBeRemoved(id.to_string()));
+        }
+        if !self.locales.peek().contains_key(id) {
+            return Err(Error::LocaleNotFound(id.to_string()));
+        }
+        self.try_update_locale(id.clone(), None)
+    }
+
+    /// Remove the locale of language `id`, see [`I18n::try_remove_locale`].
+    pub fn remove_locale(&mut self, id: &LanguageIdentifier) {
+        let result = self.try_remove_locale(id);
+        match result {
+            Ok(()) => (),
+            Err(err) => panic!("cannot remove locale {}: {}", id, err),
+        }
+    }
+
+    /// Set the locale of language `id` to `resource`, or remove it if `None`.
+    ///
+    /// If the translations need reloading, they are loaded before the locale is changed, so
+    /// that on error the locales and translations are left untouched. A resource no longer
+    /// used by any locale is replaced by the next one added.
+    fn try_update_locale(
+        &mut self,
+        id: LanguageIdentifier,
+        resource: Option<LocaleResource>,
+    ) -> Result<(), Error> {
+        let mut locales = self.locales.peek().clone();
+        locales.remove(&id);
+        let mut locale_resources = None;
+        if let Some(resource) = resource {
+            let current = self.locale_resources.peek();
+            let index = match current.iter().position(|r| *r == resource) {
+                Some(index) => index,
+                None => {
+                    let mut resources = current.clone();
+                    let used: HashSet<usize> = locales
+                        .values()
+                        .chain(self.namespaces.peek().values().flat_map(HashMap::values))
+                        .copied()
+                        .collect();
+                    let index = match (0..resources.len()).find(|i| !used.contains(i)) {
+                        Some(index) => {
+                            resources[index] = resource;
+                            index
+                        }
+                        None => {
+                            resources.push(resource);
+                            resources.len() - 1
+                        }
+                    };
+                    locale_resources = Some(resources);
+                    index
+                }
+            };
+            locales.insert(id.clone(), index);
+        }
+
+        let bundles = self.try_create_linked_bundles(
+            |i18n| i18n.is_in_use(&id),
+            locale_resources
+                .as_deref()
+                .unwrap_or(&self.locale_resources.peek()),
+            &locales,
+            &self.overrides.peek(),
+        )?;
+
+        if let Some(locale_resources) = locale_resources {
+            self.locale_resources.set(locale_resources);
+        }
+        self.locales.set(locales);
+        for mut i18n in self.linked.peek().clone() {
+            i18n.language_bundles.write().clear();
+        }
+        for (mut i18n, bundle) in bundles {
+            i18n.set_active_bundle(bundle);
+        }
+        Ok(())
+    }
+
+    /// Create the active bundles, from `locale_resources`, `locales` and `overrides`, of the linked `I18n`s (`self`
+    /// and the scopes sharing its locales) which need reloading.
+    fn try_create_linked_bundles(
+        &self,
+        reload: impl Fn(&I18n) -> bool,
+        locale_resources: &[LocaleResource],
+        locales: &HashMap<LanguageIdentifier, usize>,
+        overrides: &Overrides,
+    ) -> Result<Vec<(I18n, ActiveBundle)>, Error> {
+        self.linked
+            .peek()
+            .iter()
+            .filter(|i18n| reload(i18n))
+            .map(|i18n| {
+                let bundle = try_create_bundle(
+                    &i18n.selected_language.peek(),
+                    &i18n.fallback_language.peek(),
+                    locale_resources,
+                    locales,
+                    self.lenient_parsing,
+                    overrides.namespace(None),
+                )?;
+                Ok((*i18n, bundle))
+            })
+            .collect()
+    }
+
+    /// Whether the translations of the selected language include those of language `id`.
+    fn is_in_use(&self, id: &LanguageIdentifier) -> bool {
+        self.fallback_language.peek().as_ref() == Some(id)
+            || resolution_chain(&self.selected_language.peek()).contains(id)
+    }
+
+    /// Override message `msg` (`[namespace:]message-id[.attribute-id]`) in language `id` with
+    /// the Fluent `pattern`, e.g. to hot-fix a string without shipping a new resource:
+    ///
+    /// ```rust,ignore
+    /// i18n.try_set_override(langid!("en"), "login.placeholder", "Your work email")?;
+    /// ```
+    ///
+    /// An override takes precedence over the resources of its language, but not over those of
+    /// a more specific language (e.g. an override in `en` over `en-GB`'s resources). Overriding
+    /// an attribute keeps the message's value and other attributes.
+    pub fn try_set_override(
+        &mut self,
+        id: LanguageIdentifier,
+        msg: &str,
+        pattern: &str,
+    ) -> Result<(), Error> {
+        let mut overrides = self.overrides.peek().clone();
+        overrides.try_set(&id, msg, pattern)?;
+        self.try_update_overrides(
+            overrides,
+            Self::decompose_identifier(msg)?.namespace,
+            Some(&id),
+        )
+    }
+
+    /// Override message `msg` in language `id`, see [`I18n::try_set_override`].
+    pub fn set_override(&mut self, id: LanguageIdentifier, msg: &str, pattern: &str) {
+        let result = self.try_set_override(id, msg, pattern);
+        match result {
+            Ok(()) => (),
+            Err(err) => panic!("cannot override {}: {}", msg, err),
+        }
+    }
+
+    /// Remove the override of message `msg` in language `id`, if any.
+    pub fn try_clear_override(&mut self, id: &LanguageIdentifier, msg: &str) -> Result<(), Error> {
+        let mut overrides = self.overrides.peek().clone();
+        if !overrides.remove(id, msg) {
+            return Ok(());
+        }
+        self.try_update_overrides(
+            overrides,
+            Self::decompose_identifier(msg)?.namespace,
+            Some(id),
+        )
+    }
+
+    /// Remove the override of message `msg` in language `id`, see [`I18n::try_clear_override`].
+    pub fn clear_override(&mut self, id: &LanguageIdentifier, msg: &str) {
+        let result = self.try_clear_override(id, msg);
+        match result {
+            Ok(()) => (),
+            Err(err) => panic!("cannot clear override of {}: {}", msg, err),
+        }
+    }
+
+    /// Remove all overrides.
+    pub fn try_clear_overrides(&mut self) -> Result<(), Error> {
+        self.try_update_overrides(Overrides::default(), None, None)
+    }
+
+    /// Remove all overrides, see [`I18n::try_clear_overrides`].
+    pub fn clear_overrides(&mut self) {
+        let result = self.try_clear_overrides();
+        match result {
+            Ok(()) => (),
+            Err(err) => panic!("cannot clear overrides: {}", err),
+        }
+    }
+
+    /// The overrides of language `id` in `namespace` (or outside of namespaces if `None`), as
+    /// a Fluent resource which can be folded back into the locale's resource.
+    pub fn export_overrides(&self, id: &LanguageIdentifier, namespace: Option<&str>) -> String {
+        self.overrides.read().to_ftl(id, namespace)
+    }
+
+    /// Replace the overrides after a change of the overrides of language `id` (or of all
+    /// languages if `None`) in `namespace`.
+    ///
+    /// As with [`I18n::try_set_language`], the translations are reloaded first if needed, so
+    /// that on error the overrides are left untouched.
+    fn try_update_overrides(
+        &mut self,
+        overrides: Overrides,
+        namespace: Option<&str>,
+        id: Option<&LanguageIdentifier>,
+    ) -> Result<(), Error> {
+        let reload = |i18n: &I18n| match (namespace, id) {
+            (Some(_), _) => false,
+            (None, Some(id)) => i18n.is_in_use(id),
+            (None, None) => true,
+        };
+        let bundles = self.try_create_linked_bundles(
+            reload,
             &self.locale_resources.peek(),
             &self.locales.peek(),
+            &overrides,
         )?;
 
-        self.active_bundle.set(bundle);
+        self.overrides.set(overrides);
+        for mut i18n in self.linked.peek().clone() {
+            i18n.language_bundles.write().clear();
+            // Namespace bundles are reloaded on their next use.
+            if let Some(namespace) = namespace {
+                i18n.namespace_bundles.write().remove(namespace);
+            }
+        }
+        for (mut i18n, bundle) in bundles {
+            i18n.set_active_bundle(bundle);
+        }
         Ok(())
     }
+
+    /// Register a `callback` to be called with the previous and new language, after the
+    /// selected language has changed and its translations are active.
+    ///
+    /// Components should prefer [`use_language_changed`], which removes the listener when the
+    /// component is dropped.
+    pub fn on_language_changed(
+        &mut self,
+        callback: impl Fn(&LanguageIdentifier, &LanguageIdentifier) + 'static,
+    ) -> LanguageChangedListenerId {
+        let mut listeners = self.language_changed_listeners.write();
+        let id = listeners.next_id;
+        listeners.next_id += 1;
+        listeners.listeners.push((id, Rc::new(callback)));
+        LanguageChangedListenerId(id)
+    }
+
+    /// Remove a listener registered with [`I18n::on_language_changed`].
+    pub fn remove_language_changed_listener(&mut self, id: LanguageChangedListenerId) {
+        if let Ok(mut listeners) = self.language_changed_listeners.try_write() {
+            listeners
+                .listeners
+                .retain(|(listener_id, _)| *listener_id != id.0);
+        }
+    }
+
+    fn notify_language_changed(&self, previous: &LanguageIdentifier) {
+        let current = self.selected_language.peek().clone();
+        if *previous == current {
+            return;
+        }
+
+        // Listeners are cloned so that they may (un)register listeners themselves.
+        let listeners: Vec<LanguageChangedListener> = self
+            .language_changed_listeners
+            .read()
+            .listeners
+            .iter()
+            .map(|(_, listener)| listener.clone())
+            .collect();
+        listeners
+            .iter()
+            .for_each(|listener| listener(previous, &current));
+    }
+
+    fn try_create_active_bundle(
+        &self,
+        selected_language: &LanguageIdentifier,
+        fallback_language: &Option<LanguageIdentifier>,
+    ) -> Result<ActiveBundle, Error> {
+        try_create_bundle(
+            selected_language,
+            fallback_language,
+            &self.locale_resources.peek(),
+            &self.locales.peek(),
+            self.lenient_parsing,
+            self.overrides.peek().namespace(None),
+        )
+    }
+
+    fn set_active_bundle(&mut self, bundle: ActiveBundle) {
+        self.active_bundle.set(bundle);
+        self.namespace_bundles.write().clear();
+    }
+}
+
+/// Translate all attributes of message `message_id` from `active`, see
+/// [`I18n::try_translate_attributes`].
+fn translate_attributes_from_bundle(
+    active: &ActiveBundle,
+    message_id: &str,
+    args: Option<&FluentArgs>,
+) -> Result<HashMap<String, String>, Error> {
+    let entry = active
+        .get_entry(message_id)
+        .ok_or_else(|| active.message_id_not_found(message_id))?;
+
+    entry
+        .attributes()
+        .into_iter()
+        .map(|(id, pattern)| {
+            let mut errors = vec![];
+            let translation = active
+                .bundle
+                .format_pattern(pattern, args, &mut errors)
+                .to_string();
+            (errors.is_empty())
+                .then(|| (id.to_string(), translation))
+                .ok_or_else(|| Error::FluentErrorsDetected {
+                    key: format!("{}.{}", message_id, id),
+                    language: active.language().clone(),
+                    errors,
+                })
+        })
+        .collect()
+}
+
+/// Convert translated attributes (see [`I18n::try_translate_attributes`]) into element
+/// attributes, sorted by name, which can be spread onto an element.
+///
+/// ```rust,ignore
+/// rsx! {
+///     input { r#type: "email", ..element_attributes(i18n.translate_attributes("login-email", None)) }
+/// }
+/// ```
+///
+/// Attribute names must be `'static`, so each distinct name is allocated once and kept for
+/// the lifetime of the program.
+pub fn element_attributes(attributes: HashMap<String, String>) -> Vec<Attribute> {
+    thread_local! {
+        static NAMES: RefCell<HashSet<&'static str>> = RefCell::new(HashSet::new());
+    }
+
+    let mut attributes: Vec<_> = attributes.into_iter().collect();
+    attributes.sort();
+    attributes
+        .into_iter()
+        .map(|(name, value)| {
+            let name = NAMES.with_borrow_mut(|names| match names.get(name.as_str()) {
+                Some(name) => *name,
+                None => {
+                    let name: &'static str = Box::leak(name.into_boxed_str());
+                    names.insert(name);
+                    name
+                }
+            });
+            Attribute::new(name, value, None, false)
+        })
+        .collect()
+}
+
+/// The parts of a message identifier, see [`I18n::decompose_identifier`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessageKey<'a> {
+    pub namespace: Option<&'a str>,
+    pub message_id: Cow<'a, str>,
+    pub attribute_name: Option<&'a str>,
+}
+
+impl MessageKey<'_> {
+    /// Whether the message id is a term id, e.g. `-brand-name`.
+    pub fn is_term(&self) -> bool {
+        self.message_id.starts_with('-')
+    }
+
+    /// The message id when the attribute id is instead read as part of the message id, e.g.
+    /// `settings-profile-title` for `settings.profile.title`. `None` without attribute id.
+    pub fn flattened_id(&self) -> Option<String> {
+        self.attribute_name
+            .map(|attribute_name| format!("{}-{}", self.message_id, attribute_name))
+    }
+
+    /// Whether `.` in the message id were read as `-`, i.e. the key has more than one `.`.
+    fn has_escaped_id(&self) -> bool {
+        matches!(self.message_id, Cow::Owned(_))
+    }
+
+    /// The whole key as a message id, for lookups that do not take an attribute id.
+    fn whole_id(&self) -> Cow<'_, str> {
+        match self.flattened_id() {
+            Some(id) => Cow::Owned(id),
+            None => Cow::Borrowed(&self.message_id),
+        }
+    }
+}
+
+/// A best-effort translation, together with the errors reported while formatting it, see
+/// [`I18n::translate_with_diagnostics`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Translation {
+    /// The formatted text, in which unresolved placeables are shown as e.g. `{$name}`.
+    pub text: String,
+    /// The errors reported by Fluent, such as unknown variables.
+    pub errors: Vec<FluentError>,
+}
+
+pub(crate) fn try_translate_from_bundle(
+    active: &ActiveBundle,
+    msg: &str,
+    args: Option<&FluentArgs>,
+) -> Result<String, Error> {
+    let Translation { text, errors } = try_format_from_bundle(active, msg, args)?;
+    (errors.is_empty())
+        .then_some(text)
+        .ok_or_else(|| Error::FluentErrorsDetected {
+            key: msg.to_string(),
+            language: active.language().clone(),
+            errors,
+        })
+}
+
+fn try_format_from_bundle(
+    active: &ActiveBundle,
+    msg: &str,
+    args: Option<&FluentArgs>,
+) -> Result<Translation, Error> {
+    let key = I18n::decompose_identifier(msg)?;
+
+    // A missing attribute of an existing message is only looked up as a whole key if the key
+    // has dots read as `-`, so that e.g. a typo in `login.placeholder` is still reported.
+    let pattern = match try_get_pattern(active, &key, msg) {
+        Err(err @ Error::MessageIdNotFound { .. }) => try_get_flattened_pattern(active, &key, err)?,
+        Err(err @ Error::AttributeIdNotFound(_)) if key.has_escaped_id() => {
+            try_get_flattened_pattern(active, &key, err)?
+        }
+        result => result?,
+    };
+
+    let mut errors = vec![];
+    let text = active
+        .bundle
+        .format_pattern(pattern, args, &mut errors)
+        .to_string();
+
+    Ok(Translation { text, errors })
+}
+
+fn try_get_flattened_pattern<'b>(
+    active: &'b ActiveBundle,
+    key: &MessageKey,
+    err: Error,
+) -> Result<&'b ast::Pattern<&'b str>, Error> {
+    key.flattened_id()
+        .and_then(|id| active.get_entry(&id)?.value())
+        .ok_or(err)
+}
+
+fn try_get_pattern<'b>(
+    active: &'b ActiveBundle,
+    key: &MessageKey,
+    msg: &str,
+) -> Result<&'b ast::Pattern<&'b str>, Error> {
+    let entry = active
+        .get_entry(&key.message_id)
+        .ok_or_else(|| active.message_id_not_found(&key.message_id))?;
+
+    match key.attribute_name {
+        Some(attribute_name) => entry
+            .attribute(attribute_name)
+            .ok_or_else(|| Error::AttributeIdNotFound(msg.to_string())),
+        None => entry
+            .value()
+            .ok_or_else(|| Error::MessagePatternNotFound(key.message_id.to_string())),
+    }
+}
+
+/// The bundle for the selected language, together with the language of the locale that
+/// provided each message.
+pub(crate) struct ActiveBundle {
+    pub(crate) bundle: FluentBundle<Rc<FluentResource>>,
+    message_languages: HashMap<String, LanguageIdentifier>,
+    /// The resources added to the bundle, in order, to look up terms, which are private to
+    /// the bundle.
+    resources: Vec<Rc<FluentResource>>,
+    /// The syntax errors of resources added despite them, with lenient parsing.
+    pub(crate) diagnostics: Vec<Error>,
+}
+
+/// The Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Bundles by language and optional namespace.
+pub(crate) type LanguageBundles = HashMap<(LanguageIdentifier, Option<String>), ActiveBundle>;
+
+/// A message or term of an [`ActiveBundle`].
+pub(crate) enum Entry<'b> {
+    Message(FluentMessage<'b>),
+    Term(&'b ast::Term<&'b str>),
 }
 
-fn try_create_bundle(
+impl<'b> Entry<'b> {
+    pub(crate) fn value(&self) -> Option<&'b ast::Pattern<&'b str>> {
+        match self {
+            Self::Message(message) => message.value(),
+            Self::Term(term) => Some(&term.value),
+        }
+    }
+
+    fn attribute(&self, id: &str) -> Option<&'b ast::Pattern<&'b str>> {
+        match self {
+            Self::Message(message) => message.get_attribute(id).map(|a| a.value()),
+            Self::Term(term) => term
+                .attributes
+                .iter()
+                .find(|attribute| attribute.id.name == id)
+                .map(|attribute| &attribute.value),
+        }
+    }
+
+    pub(crate) fn attributes(&self) -> Vec<(&'b str, &'b ast::Pattern<&'b str>)> {
+        match self {
+            Self::Message(message) => message.attributes().map(|a| (a.id(), a.value())).collect(),
+            Self::Term(term) => term
+                .attributes
+                .iter()
+                .map(|attribute| (attribute.id.name, &attribute.value))
+                .collect(),
+        }
+    }
+}
+
+impl ActiveBundle {
+    /// The language of the bundle.
+    fn language(&self) -> &LanguageIdentifier {
+        &self.bundle.locales[0]
+    }
+
+    /// A [`Error::MessageIdNotFound`] for `id`, suggesting the most similar message id.
+    fn message_id_not_found(&self, id: &str) -> Error {
+        let max_distance = (id.chars().count() / 3).max(1);
+        let suggestion = self
+            .message_languages
+            .keys()
+            .map(|candidate| (edit_distance(id, candidate), candidate))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .min()
+            .map(|(_, candidate)| candidate.clone());
+
+        Error::MessageIdNotFound {
+            key: id.to_string(),
+            suggestion,
+        }
+    }
+
+    /// Get the message, or the term if `id` starts with `-`.
+    pub(crate) fn get_entry(&self, id: &str) -> Option<Entry<'_>> {
+        let Some(term_id) = id.strip_prefix('-') else {
+            return self.bundle.get_message(id).map(Entry::Message);
+        };
+
+        // Later resources override earlier ones.
+        self.resources.iter().rev().find_map(|resource| {
+            resource.entries().find_map(|entry| match entry {
+                ast::Entry::Term(term) if term.id.name == term_id => Some(Entry::Term(term)),
+                _ => None,
+            })
+        })
+    }
+}
+
+pub(crate) fn try_create_namespace_bundle(
+    namespace: &str,
+    selected_language: &LanguageIdentifier,
+    fallback_language: &Option<LanguageIdentifier>,
+    locale_resources: &[LocaleResource],
+    namespaces: &HashMap<String, HashMap<LanguageIdentifier, usize>>,
+    lenient_parsing: bool,
+    overrides: Option<&NamespaceOverrides>,
+) -> Result<ActiveBundle, Error> {
+    let locales = namespaces
+        .get(namespace)
+        .ok_or_else(|| Error::NamespaceNotFound(namespace.to_string()))?;
+    try_create_bundle(
+        selected_language,
+        fallback_language,
+        locale_resources,
+        locales,
+        lenient_parsing,
+        overrides,
+    )
+}
+
+/// The languages whose locales provide the messages for `id`, from least to most specific.
+fn resolution_chain(id: &LanguageIdentifier) -> [LanguageIdentifier; 4] {
+    let (language, script, region, variants) = id.clone().into_parts();
+    [
+        LanguageIdentifier::from_parts(language, None, None, &[]),
+        LanguageIdentifier::from_parts(language, script, None, &[]),
+        LanguageIdentifier::from_parts(language, script, region, &[]),
+        LanguageIdentifier::from_parts(language, script, region, &variants),
+    ]
+}
+
+/// Parse a resource into its valid entries, together with its syntax errors, if any.
+fn parse_resource(
+    langid: &LanguageIdentifier,
+    resource: &LocaleResource,
+) -> Result<(FluentResource, Option<Error>), Error> {
+    match FluentResource::try_new(resource.try_to_resource_string()?) {
+        Ok(parsed) => Ok((parsed, None)),
+        Err((partial, errors)) => {
+            let error = Error::ResourceParseFailed {
+                language: langid.clone(),
+                origin: resource.origin(),
+                errors: errors
+                    .into_iter()
+                    .map(|error| ResourceParseError::new(partial.source(), error))
+                    .collect(),
+            };
+            Ok((partial, Some(error)))
+        }
+    }
+}
+
+pub(crate) fn try_create_bundle(
     selected_language: &LanguageIdentifier,
     fallback_language: &Option<LanguageIdentifier>,
     locale_resources: &[LocaleResource],
     locales: &HashMap<LanguageIdentifier, usize>,
-) -> Result<FluentBundle<FluentResource>, Error> {
-    let add_resource = move |bundle: &mut FluentBundle<FluentResource>,
-                             langid: &LanguageIdentifier,
-                             locale_resources: &[LocaleResource]| {
+    lenient_parsing: bool,
+    overrides: Option<&NamespaceOverrides>,
+) -> Result<ActiveBundle, Error> {
+    let add_resource =
+        |active: &mut ActiveBundle, langid: &LanguageIdentifier, resource: FluentResource| {
+            let resource = Rc::new(resource);
+            for entry in resource.entries() {
+                if let ast::Entry::Message(message) = entry {
+                    active
+                        .message_languages
+                        .insert(message.id.name.to_string(), langid.clone());
+                }
+            }
+            active.bundle.add_resource_overriding(resource.clone());
+            active.resources.push(resource);
+        };
+    let add_locale = move |active: &mut ActiveBundle,
+                           langid: &LanguageIdentifier,
+                           locale_resources: &[LocaleResource]| {
         if let Some(&i) = locales.get(langid) {
-            let resource = &locale_resources[i];
-            let resource =
-                FluentResource::try_new(resource.try_to_resource_string()?).map_err(|e| {
-                    Error::FluentErrorsDetected(format!("resource langid: {}\n{:#?}", langid, e))
-                })?;
-            bundle.add_resource_overriding(resource);
+            let resource = match parse_resource(langid, &locale_resources[i])? {
+                (parsed, None) => parsed,
+                (partial, Some(error)) if lenient_parsing => {
+                    active.diagnostics.push(error);
+                    partial
+                }
+                (_, Some(error)) => return Err(error),
+            };
+            add_resource(active, langid, resource);
         };
+        // Overrides take precedence over the language's resources.
+        if let Some(messages) = overrides.and_then(|overrides| overrides.get(langid)) {
+            let resource = override_resource(active, messages);
+            add_resource(active, langid, resource);
+        }
         Ok(())
     };
 
-    let mut bundle = FluentBundle::new(vec![selected_language.clone()]);
-    if let Some(fallback_language) = fallback_language {
-        add_resource(&mut bundle, fallback_language, locale_resources)?;
+    let mut active = ActiveBundle {
+        bundle: FluentBundle::new(vec![selected_language.clone()]),
+        message_languages: HashMap::new(),
+        resources: Vec::new(),
+        diagnostics: Vec::new(),
+    };
+    // The fallback first, so that any other locale overrides it. A repeated language (e.g. a
+    // fallback that is also the selected language) is only added once, at its last position.
+    let mut languages: Vec<_> = fallback_language.iter().cloned().collect();
+    languages.extend(resolution_chain(selected_language));
+    let mut seen = HashSet::new();
+    languages.reverse();
+    languages.retain(|langid| seen.insert(langid.clone()));
+    languages.reverse();
+    for langid in &languages {
+        add_locale(&mut active, langid, locale_resources)?;
     }
 
-    let (language, script, region, variants) = selected_language.clone().into_parts();
-    let variants_lang = LanguageIdentifier::from_parts(language, script, region, &variants);
-    let region_lang = LanguageIdentifier::from_parts(language, script, region, &[]);
-    let script_lang = LanguageIdentifier::from_parts(language, script, None, &[]);
-    let language_lang = LanguageIdentifier::from_parts(language, None, None, &[]);
-
-    add_resource(&mut bundle, &language_lang, locale_resources)?;
-    add_resource(&mut bundle, &script_lang, locale_resources)?;
-    add_resource(&mut bundle, &region_lang, locale_resources)?;
-    add_resource(&mut bundle, &variants_lang, locale_resources)?;
-
     /* Add this code when the fluent crate includes FluentBundle::add_builtins.
      * This will allow the use of built-in functions like `NUMBER` and `DATETIME`.
      * See [Fluent issue](https://github.com/projectfluent/fluent-rs/issues/181) for more information.
@@ -474,19 +1967,44 @@ fn try_create_bundle(
         .map_err(|e| Error::FluentErrorsDetected(e.to_string()))?;
     */
 
-    Ok(bundle)
+    Ok(active)
 }
 
 pub fn i18n() -> I18n {
     consume_context()
 }
 
+/// Call `callback` with the previous and new language whenever the selected language changes.
+///
+/// This is useful to reload language dependent data, update window titles or log analytics.
+/// The listener is removed when the component is dropped.
+pub fn use_language_changed(
+    callback: impl FnMut((LanguageIdentifier, LanguageIdentifier)) + 'static,
+) {
+    let callback = use_callback(callback);
+    let mut i18n = use_hook(i18n);
+    let id = use_hook(|| {
+        i18n.on_language_changed(move |previous, current| {
+            callback((previous.clone(), current.clone()))
+        })
+    });
+    use_drop(move || i18n.remove_language_changed_listener(id));
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use pretty_assertions::assert_eq;
     use unic_langid::langid;
 
+    #[test]
+    fn edit_distance_counts_edits() {
+        assert_eq!(edit_distance("welcome", "welcome"), 0);
+        assert_eq!(edit_distance("welcom", "welcome"), 1);
+        assert_eq!(edit_distance("hello", "jello"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
     #[test]
     fn can_add_locale_to_config_explicit_locale() {
         const LANG_A: LanguageIdentifier = langid!("la-LA");
@@ -507,6 +2025,10 @@ mod test {
                     LocaleResource::Path(PathBuf::new()),
                 ],
                 locales: HashMap::from([(LANG_B, 0), (LANG_C, 1)]),
+                namespaces: HashMap::new(),
+                missing_translation_policy: MissingTranslationPolicy::default(),
+                strict_validation: false,
+                lenient_parsing: false,
             }
         );
     }
@@ -531,6 +2053,10 @@ mod test {
                     LocaleResource::Path(PathBuf::new())
                 ],
                 locales: HashMap::from([(LANG_B, 0), (LANG_C, 1)]),
+                namespaces: HashMap::new(),
+                missing_translation_policy: MissingTranslationPolicy::default(),
+                strict_validation: false,
+                lenient_parsing: false,
             }
         );
     }
@@ -549,6 +2075,10 @@ mod test {
                 fallback: None,
                 locale_resources: vec![LocaleResource::Static("lang = lang_b")],
                 locales: HashMap::from([(LANG_B, 0)]),
+                namespaces: HashMap::new(),
+                missing_translation_policy: MissingTranslationPolicy::default(),
+                strict_validation: false,
+                lenient_parsing: false,
             }
         );
     }
@@ -571,6 +2101,10 @@ mod test {
                 fallback: None,
                 locale_resources: vec![LocaleResource::Static(shared_string)],
                 locales: HashMap::from([(LANG_B, 0), (LANG_C, 0)]),
+                namespaces: HashMap::new(),
+                missing_translation_policy: MissingTranslationPolicy::default(),
+                strict_validation: false,
+                lenient_parsing: false,
             }
         );
     }
@@ -592,6 +2126,10 @@ mod test {
                     "./test/data/fallback/la.ftl"
                 ))],
                 locales: HashMap::from([(LANG_C, 0)]),
+                namespaces: HashMap::new(),
+                missing_translation_policy: MissingTranslationPolicy::default(),
+                strict_validation: false,
+                lenient_parsing: false,
             }
         );
     }
@@ -615,6 +2153,10 @@ mod test {
                 fallback: None,
                 locale_resources: vec![LocaleResource::Path(shared_pathbuf)],
                 locales: HashMap::from([(LANG_B, 0), (LANG_C, 0)]),
+                namespaces: HashMap::new(),
+                missing_translation_policy: MissingTranslationPolicy::default(),
+                strict_validation: false,
+                lenient_parsing: false,
             }
         );
     }
@@ -700,4 +2242,36 @@ mod test {
         let config = I18nConfig::new(LANG_A).try_with_auto_locales(pathbuf);
         assert_eq!(config.is_err(), true);
     }
+
+    #[test]
+    fn unused_locale_resources_are_reused() {
+        const EN: LanguageIdentifier = langid!("en");
+        const ES: LanguageIdentifier = langid!("es");
+        const FR: LanguageIdentifier = langid!("fr");
+
+        let dom = VirtualDom::new(|| rsx! {});
+        dom.in_runtime(|| {
+            ScopeId::ROOT.in_runtime(|| {
+                let mut i18n =
+                    I18n::try_from_config(I18nConfig::new(EN).with_locale((EN, "hello = Hello")))
+                        .unwrap();
+                for resource in ["hello = Hi", "hello = Hey", "hello = Hello"] {
+                    i18n.replace_locale((EN, resource));
+                }
+                i18n.add_locale((ES, "hello = Hola"));
+                i18n.remove_locale(&ES);
+                i18n.add_locale((FR, "hello = Bonjour"));
+                assert!(i18n.try_replace_locale((EN, "hello = Hi\nbroken")).is_err());
+
+                assert_eq!(
+                    *i18n.locale_resources.peek(),
+                    vec![
+                        LocaleResource::Static("hello = Hello"),
+                        LocaleResource::Static("hello = Bonjour"),
+                    ]
+                );
+                assert_eq!(i18n.translate("hello"), "Hello");
+            })
+        });
+    }
 }
@@ -0,0 +1,91 @@
+mod common;
+use common::*;
+
+use dioxus_i18n::{
+    fluent::FluentArgs,
+    prelude::{element_attributes, use_init_i18n, I18n, I18nConfig},
+};
+use unic_langid::{langid, LanguageIdentifier};
+
+use std::collections::HashMap;
+
+#[test]
+fn translate_attributes_with_args() {
+    test_hook(i18n, |value, proxy| {
+        let mut args = FluentArgs::new();
+        args.set("domain", "example.com");
+        proxy.assert(
+            value.translate_attributes("login", Some(&args)),
+            HashMap::from([
+                (
+                    "placeholder".to_string(),
+                    "email@\u{2068}example.com\u{2069}".to_string(),
+                ),
+                ("aria-label".to_string(), "Login".to_string()),
+            ]),
+            "translate_attributes_with_args",
+        );
+    });
+}
+
+#[test]
+fn translate_attributes_of_message_without_attributes() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value.translate_attributes("hello", None),
+            HashMap::new(),
+            "translate_attributes_of_message_without_attributes",
+        );
+    });
+}
+
+#[test]
+fn fail_translate_attributes_with_invalid_key() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value
+                .try_translate_attributes("missing", None)
+                .unwrap_err()
+                .to_string(),
+            "message id not found for key: 'missing'".to_string(),
+            "fail_translate_attributes_with_invalid_key[missing]",
+        );
+        proxy.assert(
+            value
+                .try_translate_attributes("login.placeholder", None)
+                .unwrap_err()
+                .to_string(),
+            "message id not found for key: 'login-placeholder'".to_string(),
+            "fail_translate_attributes_with_invalid_key[attribute]",
+        );
+    });
+}
+
+#[test]
+fn element_attributes_are_sorted_by_name() {
+    test_hook(i18n, |value, proxy| {
+        let attributes = element_attributes(value.translate_attributes("search", None));
+        proxy.assert(
+            attributes.iter().map(|a| a.name).collect::<Vec<_>>(),
+            vec!["aria-label", "title"],
+            "element_attributes_are_sorted_by_name",
+        );
+    });
+}
+
+const EN: LanguageIdentifier = langid!("en");
+
+fn i18n() -> I18n {
+    let config = I18nConfig::new(EN).with_locale((
+        EN,
+        r#"hello = Hello
+login = Login
+    .placeholder = email@{$domain}
+    .aria-label = Login
+search =
+    .title = Search the site
+    .aria-label = Search
+"#,
+    ));
+    use_init_i18n(|| config)
+}
@@ -0,0 +1,68 @@
+mod common;
+use common::*;
+
+use dioxus_i18n::{
+    fluent::FluentArgs,
+    prelude::{use_init_i18n, I18n, I18nConfig},
+};
+use unic_langid::{langid, LanguageIdentifier};
+
+#[test]
+fn missing_argument_degrades_to_placeable() {
+    test_hook(i18n, |value, proxy| {
+        let translation = value
+            .translate_with_diagnostics("hello", None)
+            .expect("message exists");
+        proxy.assert(
+            translation.text,
+            "Hello, \u{2068}{$name}\u{2069}!".to_string(),
+            "missing_argument_degrades_to_placeable[text]",
+        );
+        proxy.assert(
+            translation
+                .errors
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>(),
+            vec!["Resolver error: Unknown variable: $name".to_string()],
+            "missing_argument_degrades_to_placeable[errors]",
+        );
+    });
+}
+
+#[test]
+fn no_diagnostics_with_all_arguments() {
+    test_hook(i18n, |value, proxy| {
+        let mut args = FluentArgs::new();
+        args.set("name", "Zaphod");
+        let translation = value
+            .translate_with_diagnostics("hello", Some(&args))
+            .expect("message exists");
+        proxy.assert(
+            translation.errors.is_empty(),
+            true,
+            "no_diagnostics_with_all_arguments",
+        );
+    });
+}
+
+#[test]
+fn missing_message_is_an_error() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value
+                .translate_with_diagnostics("missing", None)
+                .unwrap_err()
+                .to_string(),
+            "message id not found for key: 'missing'".to_string(),
+            "missing_message_is_an_error",
+        );
+    });
+}
+
+const EN: LanguageIdentifier = langid!("en");
+
+fn i18n() -> I18n {
+    let config = I18nConfig::new(EN).with_locale((EN, "hello = Hello, {$name}!"));
+    use_init_i18n(|| config)
+}
@@ -0,0 +1,83 @@
+use dioxus_i18n::prelude::*;
+use pretty_assertions::assert_eq;
+use unic_langid::{langid, LanguageIdentifier};
+
+use std::path::PathBuf;
+
+#[test]
+fn message_id_not_found_suggests_similar_id() {
+    let translator = Translator::new(I18nConfig::new(EN).with_locale((EN, "welcome = Welcome")));
+    let err = translator.try_translate("welcom").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "message id not found for key: 'welcom' (did you mean 'welcome'?)"
+    );
+    assert!(matches!(
+        err,
+        DioxusI18nError::MessageIdNotFound { suggestion: Some(ref s), .. } if s == "welcome"
+    ));
+}
+
+#[test]
+fn message_id_not_found_without_similar_id() {
+    let translator = Translator::new(I18nConfig::new(EN).with_locale((EN, "welcome = Welcome")));
+    assert_eq!(
+        translator.try_translate("goodbye").unwrap_err().to_string(),
+        "message id not found for key: 'goodbye'"
+    );
+}
+
+#[test]
+fn fluent_errors_are_structured() {
+    let translator =
+        Translator::new(I18nConfig::new(EN).with_locale((EN, "hello = Hello, {$name}")));
+    let err = translator.try_translate("hello").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "fluent errors during lookup of 'hello' in en:\n  Resolver error: Unknown variable: $name"
+    );
+    let DioxusI18nError::FluentErrorsDetected {
+        language, errors, ..
+    } = err
+    else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(language, EN);
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn parse_errors_have_origin_and_location() {
+    let result = Translator::try_new(
+        I18nConfig::new(EN).with_locale((EN, "hello = Hello\ninvalid entry\nbye = Bye")),
+    );
+    let Err(DioxusI18nError::ResourceParseFailed {
+        language,
+        origin,
+        errors,
+    }) = result
+    else {
+        panic!("expected a parse error");
+    };
+    assert_eq!(language, EN);
+    assert_eq!(origin, ResourceOrigin::Static);
+    assert_eq!(
+        errors
+            .iter()
+            .map(|e| (e.line, e.column))
+            .collect::<Vec<_>>(),
+        vec![(2, 9)]
+    );
+}
+
+#[test]
+fn read_errors_have_path() {
+    let path = PathBuf::from("./non-existing.ftl");
+    let result = Translator::try_new(I18nConfig::new(EN).with_locale((EN, path.clone())));
+    assert!(matches!(
+        result,
+        Err(DioxusI18nError::LocaleResourcePathReadFailed { path: ref p, .. }) if *p == path
+    ));
+}
+
+const EN: LanguageIdentifier = langid!("en");
@@ -0,0 +1,88 @@
+#![cfg(feature = "derive")]
+
+mod common;
+use common::*;
+
+use dioxus_i18n::prelude::{use_init_i18n, FluentArgs, I18n, I18nConfig, ToFluentArgs};
+use dioxus_i18n::{t, t_in};
+use pretty_assertions::assert_eq;
+use unic_langid::{langid, LanguageIdentifier};
+
+#[derive(FluentArgs)]
+#[fluent(rename_all = "kebab-case")]
+struct User<'a> {
+    name: &'a str,
+    item_count: usize,
+    #[fluent(rename = "title")]
+    honorific: Option<String>,
+    #[fluent(skip)]
+    #[allow(dead_code)]
+    password_hash: String,
+}
+
+#[derive(FluentArgs)]
+struct Product {
+    product_name: String,
+}
+
+fn user() -> User<'static> {
+    User {
+        name: "Ana",
+        item_count: 3,
+        honorific: Some("Dr.".to_string()),
+        password_hash: "secret".to_string(),
+    }
+}
+
+#[test]
+fn struct_fields_are_converted_into_args() {
+    let user = user();
+    let args = user.to_fluent_args();
+    let names: Vec<_> = args.iter().map(|(name, _)| name).collect();
+    assert_eq!(names, vec!["item-count", "name", "title"]);
+}
+
+#[test]
+fn field_names_are_kept_without_rename_all() {
+    let product = Product {
+        product_name: "Acme".to_string(),
+    };
+    let args = product.to_fluent_args();
+    assert!(args.get("product_name").is_some());
+}
+
+#[test]
+fn struct_is_passed_as_args() {
+    test_hook(i18n, |_, proxy| {
+        proxy.assert(
+            t!("cart", args: &user()),
+            "\u{2068}Dr.\u{2069} \u{2068}Ana\u{2069} has \u{2068}3\u{2069} items.".to_string(),
+            "struct_is_passed_as_args",
+        );
+    });
+}
+
+#[test]
+fn struct_is_passed_as_args_in_language() {
+    test_hook(i18n, |_, proxy| {
+        proxy.assert(
+            t_in!(&ES, "cart", args: &user()),
+            "\u{2068}Dr.\u{2069} \u{2068}Ana\u{2069} tiene \u{2068}3\u{2069} artículos."
+                .to_string(),
+            "struct_is_passed_as_args_in_language",
+        );
+    });
+}
+
+const EN: LanguageIdentifier = langid!("en");
+const ES: LanguageIdentifier = langid!("es");
+
+fn i18n() -> I18n {
+    let config = I18nConfig::new(EN)
+        .with_locale((EN, "cart = { $title } { $name } has { $item-count } items."))
+        .with_locale((
+            ES,
+            "cart = { $title } { $name } tiene { $item-count } artículos.",
+        ));
+    use_init_i18n(|| config)
+}
@@ -0,0 +1,125 @@
+mod common;
+use common::*;
+
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::{use_init_i18n, I18n, I18nConfig, MissingTranslationPolicy};
+use dioxus_i18n::t;
+use unic_langid::{langid, LanguageIdentifier};
+
+use std::{cell::RefCell, rc::Rc};
+
+#[test]
+fn global_arg_is_passed_to_translations() {
+    test_hook(i18n, |(value, _), proxy| {
+        proxy.assert(
+            value.translate("welcome"),
+            "Welcome to \u{2068}Acme\u{2069}, \u{2068}Ana\u{2069}!".to_string(),
+            "global_arg_is_passed_to_translations",
+        );
+    });
+}
+
+#[test]
+fn call_site_arg_takes_precedence() {
+    test_hook(i18n, |_, proxy| {
+        proxy.assert(
+            t!("welcome", user: "Bob"),
+            "Welcome to \u{2068}Acme\u{2069}, \u{2068}Bob\u{2069}!".to_string(),
+            "call_site_arg_takes_precedence",
+        );
+    });
+}
+
+#[test]
+fn signal_backed_arg_follows_signal() {
+    test_hook(i18n, |(value, mut user), proxy| {
+        if *user.peek() == "Ana" {
+            user.set("Carla".to_string());
+        }
+        proxy.assert(
+            value.translate("welcome"),
+            "Welcome to \u{2068}Acme\u{2069}, \u{2068}Carla\u{2069}!".to_string(),
+            "signal_backed_arg_follows_signal",
+        );
+    });
+}
+
+#[test]
+fn global_arg_selects_variant() {
+    test_hook(i18n, |(mut value, _), proxy| {
+        if value.try_translate("pronoun").is_err() {
+            value.set_global_arg("gender", "female");
+        }
+        proxy.assert(
+            value.translate("pronoun"),
+            "she".to_string(),
+            "global_arg_selects_variant",
+        );
+    });
+}
+
+#[test]
+fn removed_global_arg_is_not_passed() {
+    test_hook(i18n, |(mut value, _), proxy| {
+        value.remove_global_arg("product");
+        proxy.assert(
+            value.try_translate("welcome").is_err(),
+            true,
+            "removed_global_arg_is_not_passed",
+        );
+    });
+}
+
+#[test]
+fn missing_translation_callback_may_set_global_arg() {
+    let translations = Rc::new(RefCell::new(None));
+    let recorder = translations.clone();
+    test_hook(i18n_with_recording_callback, move |value, _| {
+        let missing = value.translate("missing");
+        recorder
+            .borrow_mut()
+            .replace((missing, value.translate("last-missing")));
+    });
+    assert_eq!(
+        translations.borrow().clone(),
+        Some((
+            "missing".to_string(),
+            "Last missing: \u{2068}missing\u{2069}".to_string(),
+        ))
+    );
+}
+
+const EN: LanguageIdentifier = langid!("en");
+
+fn i18n() -> (I18n, Signal<String>) {
+    let config = I18nConfig::new(EN).with_locale((
+        EN,
+        "welcome = Welcome to { $product }, { $user }!\n\
+         pronoun = { $gender ->\n    [female] she\n    [male] he\n   *[other] they\n}",
+    ));
+    let mut i18n = use_init_i18n(|| config);
+    let user = use_signal(|| "Ana".to_string());
+    use_hook(|| {
+        i18n.set_global_arg("product", "Acme");
+        i18n.set_global_arg_signal("user", user);
+    });
+    (i18n, user)
+}
+
+fn i18n_with_recording_callback() -> I18n {
+    let cell: Rc<RefCell<Option<I18n>>> = use_hook(|| Rc::new(RefCell::new(None)));
+    let recorder = cell.clone();
+    let policy = MissingTranslationPolicy::Callback(Rc::new(move |msg, _| {
+        // Only set once, as setting the argument rerenders the component.
+        if let Some(mut i18n) = recorder.borrow_mut().take() {
+            i18n.set_global_arg("last", msg.to_string());
+        }
+        msg.to_string()
+    }));
+    let config = I18nConfig::new(EN)
+        .with_locale((EN, "last-missing = Last missing: { $last }"))
+        .with_missing_translation_policy(policy);
+    let i18n = use_init_i18n(|| config);
+    use_hook(|| cell.borrow_mut().replace(i18n));
+    i18n
+}
@@ -69,6 +69,27 @@ fn no_locale_match_will_use_fallback() {
     });
 }
 
+#[test]
+fn message_language_is_the_language_of_the_providing_locale() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value.message_language("script"),
+            Some(langid!("la-Scpt")),
+            "message_language_is_the_language_of_the_providing_locale",
+        );
+        proxy.assert(
+            value.message_language("fallback"),
+            Some(langid!("fb-FB")),
+            "message_language_is_the_language_of_the_providing_locale",
+        );
+        proxy.assert(
+            value.message_language("invalid"),
+            None,
+            "message_language_is_the_language_of_the_providing_locale",
+        );
+    });
+}
+
 fn i18n() -> I18n {
     const FALLBACK_LANG: LanguageIdentifier = langid!("fb-FB");
     const LANGUAGE_LANG: LanguageIdentifier = langid!("la");
@@ -0,0 +1,98 @@
+mod common;
+use common::*;
+
+use dioxus_i18n::prelude::{use_init_i18n, I18n, I18nConfig};
+use unic_langid::{langid, LanguageIdentifier};
+
+#[test]
+fn has_message() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(value.has_message("hello"), true, "has_message[hello]");
+        proxy.assert(value.has_message("bye"), true, "has_message[fallback]");
+        proxy.assert(value.has_message("missing"), false, "has_message[missing]");
+        proxy.assert(
+            value.has_message("hello.tooltip"),
+            false,
+            "has_message[attribute]",
+        );
+        proxy.assert(
+            value.has_message("settings:title"),
+            true,
+            "has_message[namespace]",
+        );
+    });
+}
+
+#[test]
+fn has_attribute() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value.has_attribute("hello.tooltip"),
+            true,
+            "has_attribute[tooltip]",
+        );
+        proxy.assert(
+            value.has_attribute("hello.missing"),
+            false,
+            "has_attribute[missing]",
+        );
+        proxy.assert(
+            value.has_attribute("hello"),
+            false,
+            "has_attribute[message]",
+        );
+    });
+}
+
+#[test]
+fn message_ids_are_sorted() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value.message_ids(),
+            vec!["bye".to_string(), "hello".to_string()],
+            "message_ids_are_sorted",
+        );
+    });
+}
+
+#[test]
+fn message_ids_with_languages() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value.message_ids_with_languages(),
+            vec![("bye".to_string(), EN), ("hello".to_string(), ES)],
+            "message_ids_with_languages",
+        );
+    });
+}
+
+#[test]
+fn attributes_in_order_of_definition() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value.attributes("hello"),
+            vec!["tooltip".to_string(), "aria-label".to_string()],
+            "attributes_in_order_of_definition[hello]",
+        );
+        proxy.assert(
+            value.attributes("missing"),
+            Vec::<String>::new(),
+            "attributes_in_order_of_definition[missing]",
+        );
+    });
+}
+
+const EN: LanguageIdentifier = langid!("en");
+const ES: LanguageIdentifier = langid!("es");
+
+fn i18n() -> I18n {
+    let config = I18nConfig::new(ES)
+        .with_fallback(EN)
+        .with_locale((EN, "hello = Hello\nbye = Bye"))
+        .with_locale((
+            ES,
+            "hello = Hola\n    .tooltip = Saludo\n    .aria-label = Saludo",
+        ))
+        .with_namespaced_locale("settings", (ES, "title = Ajustes"));
+    use_init_i18n(|| config)
+}
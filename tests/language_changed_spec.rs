@@ -0,0 +1,66 @@
+mod common;
+use common::*;
+
+use dioxus_i18n::prelude::{use_init_i18n, I18n, I18nConfig};
+use unic_langid::{langid, LanguageIdentifier};
+
+use std::{cell::RefCell, rc::Rc};
+
+#[test]
+fn listener_is_called_with_previous_and_new_language() {
+    test_hook(i18n, |mut value, proxy| {
+        let changes = Rc::new(RefCell::new(vec![]));
+        let recorder = changes.clone();
+        value.on_language_changed(move |previous, current| {
+            recorder
+                .borrow_mut()
+                .push((previous.clone(), current.clone()))
+        });
+
+        value.set_language(ES);
+        proxy.assert(
+            changes.borrow().clone(),
+            vec![(EN, ES)],
+            "listener_is_called_with_previous_and_new_language",
+        );
+    });
+}
+
+#[test]
+fn listener_is_not_called_when_language_is_unchanged() {
+    test_hook(i18n, |mut value, proxy| {
+        let changes = Rc::new(RefCell::new(0));
+        let recorder = changes.clone();
+        value.on_language_changed(move |_, _| *recorder.borrow_mut() += 1);
+
+        value.set_language(EN);
+        proxy.assert(
+            *changes.borrow(),
+            0,
+            "listener_is_not_called_when_language_is_unchanged",
+        );
+    });
+}
+
+#[test]
+fn removed_listener_is_not_called() {
+    test_hook(i18n, |mut value, proxy| {
+        let changes = Rc::new(RefCell::new(0));
+        let recorder = changes.clone();
+        let id = value.on_language_changed(move |_, _| *recorder.borrow_mut() += 1);
+
+        value.remove_language_changed_listener(id);
+        value.set_language(ES);
+        proxy.assert(*changes.borrow(), 0, "removed_listener_is_not_called");
+    });
+}
+
+const EN: LanguageIdentifier = langid!("en");
+const ES: LanguageIdentifier = langid!("es");
+
+fn i18n() -> I18n {
+    let config = I18nConfig::new(EN)
+        .with_locale((EN, "hello = Hello"))
+        .with_locale((ES, "hello = Hola"));
+    use_init_i18n(|| config)
+}
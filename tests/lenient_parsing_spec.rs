@@ -0,0 +1,89 @@
+mod common;
+use common::*;
+
+use dioxus_i18n::prelude::*;
+use pretty_assertions::assert_eq;
+use unic_langid::{langid, LanguageIdentifier};
+
+#[test]
+fn valid_messages_of_broken_resource_are_translated() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            (value.translate("hello"), value.translate("goodbye")),
+            ("Hello".to_string(), "Goodbye".to_string()),
+            "valid_messages_of_broken_resource_are_translated",
+        );
+    });
+}
+
+#[test]
+fn junk_entries_are_reported_as_diagnostics() {
+    test_hook(i18n, |value, proxy| {
+        let junk: Vec<_> = value
+            .resource_diagnostics()
+            .into_iter()
+            .flat_map(|diagnostic| match diagnostic {
+                DioxusI18nError::ResourceParseFailed { errors, .. } => errors,
+                _ => vec![],
+            })
+            .map(|error| (error.line, error.junk))
+            .collect();
+        proxy.assert(
+            junk,
+            vec![(2, Some("broken".to_string()))],
+            "junk_entries_are_reported_as_diagnostics",
+        );
+    });
+}
+
+#[test]
+fn namespace_diagnostics_are_reported_once_loaded() {
+    test_hook(i18n, |value, proxy| {
+        value.translate("settings:title");
+        proxy.assert(
+            value.resource_diagnostics().len(),
+            2,
+            "namespace_diagnostics_are_reported_once_loaded",
+        );
+    });
+}
+
+#[test]
+fn translator_keeps_valid_messages() {
+    let translator = Translator::new(config().with_lenient_parsing());
+    assert_eq!(translator.translate("goodbye"), "Goodbye");
+    assert_eq!(translator.resource_diagnostics().len(), 1);
+}
+
+#[test]
+fn junk_entries_are_reported_once_when_the_fallback_is_selected() {
+    let translator = Translator::new(
+        I18nConfig::new(EN_US)
+            .with_fallback(EN_US)
+            .with_locale((EN_US, "hello = Hello\nbroken\n"))
+            .with_lenient_parsing(),
+    );
+    assert_eq!(translator.translate("hello"), "Hello");
+    assert_eq!(translator.resource_diagnostics().len(), 1);
+}
+
+#[test]
+fn broken_resource_fails_without_lenient_parsing() {
+    assert!(matches!(
+        Translator::try_new(config()),
+        Err(DioxusI18nError::ResourceParseFailed { .. })
+    ));
+}
+
+const EN: LanguageIdentifier = langid!("en");
+const EN_US: LanguageIdentifier = langid!("en-US");
+
+fn config() -> I18nConfig {
+    I18nConfig::new(EN)
+        .with_locale((EN, "hello = Hello\nbroken\ngoodbye = Goodbye\n"))
+        .with_namespaced_locale("settings", (EN, "title = Settings\n-term\n"))
+}
+
+fn i18n() -> I18n {
+    use_init_i18n(|| config().with_lenient_parsing())
+}
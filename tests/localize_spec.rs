@@ -0,0 +1,125 @@
+#![cfg(feature = "derive")]
+
+mod common;
+use common::*;
+
+use dioxus_i18n::prelude::{use_init_i18n, I18n, I18nConfig, Localize};
+use unic_langid::{langid, LanguageIdentifier};
+
+#[derive(Localize)]
+enum OrderStatus {
+    Pending,
+    Shipped {
+        date: &'static str,
+        #[localize(skip)]
+        #[allow(dead_code)]
+        tracking_id: u64,
+    },
+    InTransit(u32, #[localize(rename = "city")] String),
+    #[localize(id = "orders.cancelled")]
+    Cancelled,
+}
+
+#[derive(Localize)]
+#[localize(prefix = "unit")]
+enum Unit {
+    Kg,
+}
+
+#[derive(Localize)]
+struct CartSummary {
+    items: usize,
+}
+
+#[test]
+fn unit_variant_is_localized() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            OrderStatus::Pending.localize(&value),
+            "Pending".to_string(),
+            "unit_variant_is_localized",
+        );
+    });
+}
+
+#[test]
+fn variant_fields_are_passed_as_args() {
+    test_hook(i18n, |value, proxy| {
+        let shipped = OrderStatus::Shipped {
+            date: "May 1",
+            tracking_id: 42,
+        };
+        let in_transit = OrderStatus::InTransit(2, "Lyon".to_string());
+        proxy.assert(
+            (shipped.localize(&value), in_transit.localize(&value)),
+            (
+                "Shipped on \u{2068}May 1\u{2069}".to_string(),
+                "\u{2068}2\u{2069} days from \u{2068}Lyon\u{2069}".to_string(),
+            ),
+            "variant_fields_are_passed_as_args",
+        );
+    });
+}
+
+#[test]
+fn variant_id_can_be_set() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            OrderStatus::Cancelled.localize(&value),
+            "Cancelled".to_string(),
+            "variant_id_can_be_set",
+        );
+    });
+}
+
+#[test]
+fn prefix_replaces_type_name() {
+    test_hook(i18n, |_, proxy| {
+        proxy.assert(
+            Unit::Kg.localized(),
+            "kg".to_string(),
+            "prefix_replaces_type_name",
+        );
+    });
+}
+
+#[test]
+fn struct_is_localized() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            CartSummary { items: 3 }.localize(&value),
+            "\u{2068}3\u{2069} items".to_string(),
+            "struct_is_localized",
+        );
+    });
+}
+
+#[test]
+fn missing_message_is_an_error() {
+    test_hook(i18n_without_messages, |value, proxy| {
+        proxy.assert(
+            OrderStatus::Pending.try_localize(&value).is_err(),
+            true,
+            "missing_message_is_an_error",
+        );
+    });
+}
+
+const EN: LanguageIdentifier = langid!("en");
+
+fn i18n() -> I18n {
+    let config = I18nConfig::new(EN).with_locale((
+        EN,
+        "order-status-pending = Pending\n\
+         order-status-shipped = Shipped on { $date }\n\
+         order-status-in-transit = { $arg0 } days from { $city }\n\
+         orders =\n    .cancelled = Cancelled\n\
+         unit-kg = kg\n\
+         cart-summary = { $items } items",
+    ));
+    use_init_i18n(|| config)
+}
+
+fn i18n_without_messages() -> I18n {
+    use_init_i18n(|| I18nConfig::new(EN).with_locale((EN, "")))
+}
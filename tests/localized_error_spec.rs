@@ -0,0 +1,140 @@
+mod common;
+use common::*;
+
+use dioxus_i18n::prelude::{
+    use_init_i18n, DioxusI18nError, I18n, I18nConfig, LocalizedError, MissingTranslationPolicy,
+};
+use unic_langid::{langid, LanguageIdentifier};
+
+use std::{cell::RefCell, rc::Rc};
+
+#[cfg(feature = "derive")]
+#[derive(Debug, thiserror::Error, LocalizedError)]
+enum LoginError {
+    #[error("wrong password for {user}")]
+    InvalidPassword { user: String },
+    #[error("account locked")]
+    Locked,
+    #[error("network error")]
+    Network(#[from] std::io::Error),
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn derived_error_is_localized() {
+    test_hook(i18n, |value, proxy| {
+        let error = LoginError::InvalidPassword {
+            user: "ana".to_string(),
+        };
+        proxy.assert(
+            error.localize_error(&value),
+            "Mot de passe incorrect pour \u{2068}ana\u{2069}".to_string(),
+            "derived_error_is_localized",
+        );
+    });
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn source_is_not_passed_as_argument() {
+    test_hook(i18n, |_, proxy| {
+        let error = LoginError::from(std::io::Error::other("timeout"));
+        proxy.assert(
+            error.localized_error(),
+            "Erreur réseau".to_string(),
+            "source_is_not_passed_as_argument",
+        );
+    });
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn missing_translation_falls_back_to_display() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            (
+                LoginError::Locked.try_localize_error(&value).is_err(),
+                LoginError::Locked.localize_error(&value),
+            ),
+            (true, "account locked".to_string()),
+            "missing_translation_falls_back_to_display",
+        );
+    });
+}
+
+#[test]
+fn crate_error_is_localized() {
+    test_hook(i18n, |value, proxy| {
+        let error = DioxusI18nError::MessageIdNotFound {
+            key: "helo".to_string(),
+            suggestion: Some("hello".to_string()),
+        };
+        proxy.assert(
+            error.localize_error(&value),
+            "Aucune traduction pour \u{2068}helo\u{2069}, vouliez-vous dire \u{2068}hello\u{2069} ?"
+                .to_string(),
+            "crate_error_is_localized",
+        );
+    });
+}
+
+#[test]
+fn crate_error_without_translation_falls_back_to_display() {
+    test_hook(i18n, |value, proxy| {
+        let error = DioxusI18nError::NamespaceNotFound("admin".to_string());
+        proxy.assert(
+            error.localize_error(&value),
+            error.to_string(),
+            "crate_error_without_translation_falls_back_to_display",
+        );
+    });
+}
+
+#[test]
+fn missing_translation_policy_does_not_replace_display() {
+    let policies = [
+        MissingTranslationPolicy::HumanizedId,
+        MissingTranslationPolicy::Marker,
+        MissingTranslationPolicy::Callback(Rc::new(|msg, _| msg.to_string())),
+        MissingTranslationPolicy::PanicInDebug(Box::new(MissingTranslationPolicy::Marker)),
+    ];
+    for policy in policies {
+        let name = format!("{:?}", policy);
+        let messages = Rc::new(RefCell::new(vec![]));
+        let recorder = messages.clone();
+        let mut policy = Some(policy);
+        test_hook(
+            move || i18n_with_policy(policy.take().unwrap()),
+            move |value, _| {
+                let mut messages = recorder.borrow_mut();
+                messages.clear();
+                messages.push(
+                    DioxusI18nError::NamespaceNotFound("admin".to_string()).localize_error(&value),
+                );
+                #[cfg(feature = "derive")]
+                messages.push(LoginError::Locked.localize_error(&value));
+            },
+        );
+
+        let mut expected = vec!["namespace not found: 'admin'".to_string()];
+        #[cfg(feature = "derive")]
+        expected.push("account locked".to_string());
+        assert_eq!(*messages.borrow(), expected, "{}", name);
+    }
+}
+
+const FR: LanguageIdentifier = langid!("fr");
+
+fn i18n() -> I18n {
+    i18n_with_policy(MissingTranslationPolicy::Error)
+}
+
+fn i18n_with_policy(policy: MissingTranslationPolicy) -> I18n {
+    let config = I18nConfig::new(FR).with_missing_translation_policy(policy).with_locale((
+        FR,
+        "login-error-invalid-password = Mot de passe incorrect pour { $user }\n\
+         login-error-network = Erreur réseau\n\
+         dioxus-i18n-error-message-id-not-found = Aucune traduction pour { $key }, vouliez-vous dire { $suggestion } ?",
+    ));
+    use_init_i18n(|| config)
+}
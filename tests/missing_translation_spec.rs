@@ -0,0 +1,165 @@
+mod common;
+use common::*;
+
+use dioxus_i18n::{
+    prelude::{use_init_i18n, I18n, I18nConfig, MissingTranslationPolicy, Translator},
+    t,
+};
+use unic_langid::{langid, LanguageIdentifier};
+
+use std::{cell::RefCell, collections::HashMap, panic::AssertUnwindSafe, rc::Rc};
+
+#[test]
+fn error_policy_returns_error() {
+    test_hook(i18n(MissingTranslationPolicy::Error), |value, proxy| {
+        proxy.assert(
+            value.try_translate("new-feature").unwrap_err().to_string(),
+            "message id not found for key: 'new-feature'".to_string(),
+            "error_policy_returns_error",
+        );
+    });
+}
+
+#[test]
+fn humanized_id_policy() {
+    test_hook(i18n(MissingTranslationPolicy::HumanizedId), |_, proxy| {
+        proxy.assert(
+            t!("new-feature"),
+            "New feature".to_string(),
+            "humanized_id_policy",
+        );
+    });
+}
+
+#[test]
+fn base_language_policy() {
+    test_hook(
+        i18n(MissingTranslationPolicy::BaseLanguage(EN)),
+        |value, proxy| {
+            proxy.assert(
+                value.translate("new-feature"),
+                "New feature!".to_string(),
+                "base_language_policy[found]",
+            );
+            proxy.assert(
+                value.try_translate("missing").unwrap_err().to_string(),
+                "message id not found for key: 'missing'".to_string(),
+                "base_language_policy[missing]",
+            );
+        },
+    );
+}
+
+#[test]
+fn marker_policy() {
+    test_hook(i18n(MissingTranslationPolicy::Marker), |value, proxy| {
+        proxy.assert(
+            value.translate("hello.tooltip"),
+            "⟦hello.tooltip⟧".to_string(),
+            "marker_policy",
+        );
+    });
+}
+
+#[test]
+fn callback_policy() {
+    let policy = MissingTranslationPolicy::Callback(Rc::new(|msg, err| format!("{msg}: {err}")));
+    test_hook(i18n(policy), |value, proxy| {
+        proxy.assert(
+            value.translate("missing"),
+            "missing: message id not found for key: 'missing'".to_string(),
+            "callback_policy",
+        );
+    });
+}
+
+#[test]
+fn panic_in_debug_policy() {
+    let policy = MissingTranslationPolicy::PanicInDebug(Box::new(MissingTranslationPolicy::Marker));
+    test_hook(i18n(policy), |value, proxy| {
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| value.try_translate("missing")));
+        proxy.assert(result.is_err(), true, "panic_in_debug_policy");
+    });
+}
+
+#[test]
+fn policy_ignores_invalid_ids() {
+    test_hook(i18n(MissingTranslationPolicy::Marker), |value, proxy| {
+        proxy.assert(
+            value.try_translate("hello..x").unwrap_err().to_string(),
+            "invalid message id: 'hello..x'".to_string(),
+            "policy_ignores_invalid_ids",
+        );
+    });
+}
+
+#[test]
+fn attributes_use_base_language_policy() {
+    test_hook(
+        i18n(MissingTranslationPolicy::BaseLanguage(EN)),
+        |value, proxy| {
+            proxy.assert(
+                value.translate_attributes("email", None),
+                HashMap::from([("placeholder".to_string(), "Your email".to_string())]),
+                "attributes_use_base_language_policy",
+            );
+        },
+    );
+}
+
+#[test]
+fn attributes_use_text_policy() {
+    let calls = Rc::new(RefCell::new(vec![]));
+    let recorder = calls.clone();
+    let policy = MissingTranslationPolicy::Callback(Rc::new(move |msg, _| {
+        recorder.borrow_mut().push(msg.to_string());
+        msg.to_string()
+    }));
+    test_hook(i18n(policy), move |value, proxy| {
+        proxy.assert(
+            value.translate_attributes("email", None),
+            HashMap::new(),
+            "attributes_use_text_policy[attributes]",
+        );
+        proxy.assert(
+            calls.borrow().last().cloned(),
+            Some("email".to_string()),
+            "attributes_use_text_policy[callback]",
+        );
+    });
+}
+
+#[test]
+fn attributes_panic_in_debug_policy() {
+    let policy = MissingTranslationPolicy::PanicInDebug(Box::new(MissingTranslationPolicy::Marker));
+    test_hook(i18n(policy), |value, proxy| {
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            value.try_translate_attributes("email", None)
+        }));
+        proxy.assert(result.is_err(), true, "attributes_panic_in_debug_policy");
+    });
+}
+
+#[test]
+fn translator_uses_policy() {
+    let translator = Translator::new(config(MissingTranslationPolicy::BaseLanguage(EN)));
+    assert_eq!(translator.translate("new-feature"), "New feature!");
+}
+
+const EN: LanguageIdentifier = langid!("en");
+const ES: LanguageIdentifier = langid!("es");
+
+fn config(policy: MissingTranslationPolicy) -> I18nConfig {
+    I18nConfig::new(ES)
+        .with_locale((
+            EN,
+            "hello = Hello\nnew-feature = New feature!\nemail =\n    .placeholder = Your email",
+        ))
+        .with_locale((ES, "hello = Hola"))
+        .with_missing_translation_policy(policy)
+}
+
+fn i18n(policy: MissingTranslationPolicy) -> impl FnMut() -> I18n {
+    let mut config = Some(config(policy));
+    move || use_init_i18n(|| config.take().unwrap())
+}
@@ -0,0 +1,107 @@
+mod common;
+use common::*;
+
+use dioxus_i18n::prelude::{use_init_i18n, I18n, I18nConfig};
+use unic_langid::{langid, LanguageIdentifier};
+
+#[test]
+fn namespaced_message_is_translated() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value.translate("settings:title"),
+            "Settings".to_string(),
+            "namespaced_message_is_translated",
+        );
+    });
+}
+
+#[test]
+fn namespaced_attribute_is_translated() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value.translate("settings:title.tooltip"),
+            "Change your settings".to_string(),
+            "namespaced_attribute_is_translated",
+        );
+    });
+}
+
+#[test]
+fn namespaced_message_follows_language_change() {
+    test_hook(i18n, |mut value, proxy| {
+        // Load the namespace before changing the language.
+        if value.language() == EN {
+            value.translate("settings:title");
+            value.set_language(ES);
+        }
+        proxy.assert(
+            value.translate("settings:title"),
+            "Ajustes".to_string(),
+            "namespaced_message_follows_language_change",
+        );
+    });
+}
+
+#[test]
+fn namespaced_message_falls_back() {
+    test_hook(i18n, |mut value, proxy| {
+        value.set_language(ES);
+        proxy.assert(
+            value.translate("settings:reset"),
+            "Reset".to_string(),
+            "namespaced_message_falls_back",
+        );
+    });
+}
+
+#[test]
+fn namespaces_do_not_share_messages() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value
+                .try_translate("settings:hello")
+                .unwrap_err()
+                .to_string(),
+            "message id not found for key: 'hello'".to_string(),
+            "namespaces_do_not_share_messages[namespace]",
+        );
+        proxy.assert(
+            value.try_translate("title").unwrap_err().to_string(),
+            "message id not found for key: 'title'".to_string(),
+            "namespaces_do_not_share_messages[default]",
+        );
+    });
+}
+
+#[test]
+fn unknown_namespace_fails() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value
+                .try_translate("unknown:title")
+                .unwrap_err()
+                .to_string(),
+            "namespace not found: 'unknown'".to_string(),
+            "unknown_namespace_fails",
+        );
+    });
+}
+
+const EN: LanguageIdentifier = langid!("en");
+const ES: LanguageIdentifier = langid!("es");
+
+fn i18n() -> I18n {
+    let config = I18nConfig::new(EN)
+        .with_fallback(EN)
+        .with_locale((EN, "hello = Hello"))
+        .with_locale((ES, "hello = Hola"))
+        .with_namespaced_locale(
+            "settings",
+            (
+                EN,
+                "title = Settings\n    .tooltip = Change your settings\nreset = Reset",
+            ),
+        )
+        .with_namespaced_locale("settings", (ES, "title = Ajustes"));
+    use_init_i18n(|| config)
+}
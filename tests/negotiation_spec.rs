@@ -0,0 +1,46 @@
+mod common;
+use common::*;
+
+use dioxus_i18n::{
+    negotiate::parse_accept_language,
+    prelude::{use_init_i18n, I18n, I18nConfig},
+};
+use unic_langid::{langid, LanguageIdentifier};
+
+#[test]
+fn negotiated_language_is_selected() {
+    test_hook(i18n("es-MX;q=0.9, de;q=0.8"), |value, proxy| {
+        proxy.assert(value.language(), ES, "negotiated_language_is_selected");
+        proxy.assert(
+            value
+                .try_translate("hello")
+                .expect("test message id must exist"),
+            "Hola".to_string(),
+            "negotiated_language_is_selected",
+        );
+    });
+}
+
+#[test]
+fn initial_language_is_kept_without_negotiated_match() {
+    test_hook(i18n("de, fr;q=0.5"), |value, proxy| {
+        proxy.assert(
+            value.language(),
+            EN,
+            "initial_language_is_kept_without_negotiated_match",
+        );
+    });
+}
+
+const EN: LanguageIdentifier = langid!("en");
+const ES: LanguageIdentifier = langid!("es");
+
+fn i18n(accept_language: &'static str) -> impl FnMut() -> I18n {
+    move || {
+        let config = I18nConfig::new(EN)
+            .with_locale((EN, "hello = Hello"))
+            .with_locale((ES, "hello = Hola"))
+            .with_negotiated_language(&parse_accept_language(accept_language));
+        use_init_i18n(|| config)
+    }
+}
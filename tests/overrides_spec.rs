@@ -0,0 +1,132 @@
+mod common;
+use common::*;
+
+use dioxus_i18n::prelude::{use_init_i18n, DioxusI18nError, I18n, I18nConfig};
+use dioxus_i18n::t;
+use unic_langid::{langid, LanguageIdentifier};
+
+#[test]
+fn override_takes_precedence_over_resource() {
+    test_hook(i18n, |mut value, proxy| {
+        if value.export_overrides(&EN, None).is_empty() {
+            value.set_override(EN, "hello", "Hi, { $name }!");
+        }
+        proxy.assert(
+            t!("hello", name: "Ana"),
+            "Hi, \u{2068}Ana\u{2069}!".to_string(),
+            "override_takes_precedence_over_resource",
+        );
+    });
+}
+
+#[test]
+fn attribute_override_keeps_value_and_other_attributes() {
+    test_hook(i18n, |mut value, proxy| {
+        if value.export_overrides(&EN, None).is_empty() {
+            value.set_override(EN, "login.placeholder", "Your work email");
+        }
+        proxy.assert(
+            (
+                value.translate("login"),
+                value.translate("login.placeholder"),
+                value.translate("login.title"),
+            ),
+            (
+                "Log in".to_string(),
+                "Your work email".to_string(),
+                "Log in to your account".to_string(),
+            ),
+            "attribute_override_keeps_value_and_other_attributes",
+        );
+    });
+}
+
+#[test]
+fn more_specific_resource_takes_precedence_over_override() {
+    test_hook(i18n, |mut value, proxy| {
+        if value.language() == EN {
+            value.set_language(EN_GB);
+            value.set_override(EN, "colour", "Hue");
+        }
+        proxy.assert(
+            value.translate("colour"),
+            "Colour".to_string(),
+            "more_specific_resource_takes_precedence_over_override",
+        );
+    });
+}
+
+#[test]
+fn cleared_override_restores_resource() {
+    test_hook(i18n, |mut value, proxy| {
+        if value.export_overrides(&EN, None).is_empty() {
+            value.set_override(EN, "hello", "Hi");
+            value.set_override(EN, "colour", "Hue");
+            value.clear_override(&EN, "hello");
+        }
+        proxy.assert(
+            (t!("hello", name: "Ana"), value.translate("colour")),
+            ("Hello, \u{2068}Ana\u{2069}!".to_string(), "Hue".to_string()),
+            "cleared_override_restores_resource",
+        );
+    });
+}
+
+#[test]
+fn namespaced_message_can_be_overridden() {
+    test_hook(i18n, |mut value, proxy| {
+        if value.export_overrides(&EN, Some("settings")).is_empty() {
+            value.translate("settings:title");
+            value.set_override(EN, "settings:title", "Preferences");
+        }
+        proxy.assert(
+            value.translate("settings:title"),
+            "Preferences".to_string(),
+            "namespaced_message_can_be_overridden",
+        );
+    });
+}
+
+#[test]
+fn invalid_override_is_rejected() {
+    test_hook(i18n, |mut value, proxy| {
+        proxy.assert(
+            matches!(
+                value.try_set_override(EN, "hello", "Hi {"),
+                Err(DioxusI18nError::InvalidOverride { .. })
+            ),
+            true,
+            "invalid_override_is_rejected",
+        );
+    });
+}
+
+#[test]
+fn overrides_are_exported_as_ftl() {
+    test_hook(i18n, |mut value, proxy| {
+        if value.export_overrides(&EN, None).is_empty() {
+            value.set_override(EN, "login.placeholder", "Your work email");
+            value.set_override(EN, "hello", "Hi");
+            value.set_override(EN_GB, "colour", "Hue");
+        }
+        proxy.assert(
+            value.export_overrides(&EN, None),
+            "hello = Hi\nlogin =\n    .placeholder = Your work email\n".to_string(),
+            "overrides_are_exported_as_ftl",
+        );
+    });
+}
+
+const EN: LanguageIdentifier = langid!("en");
+const EN_GB: LanguageIdentifier = langid!("en-GB");
+
+fn i18n() -> I18n {
+    let config = I18nConfig::new(EN)
+        .with_locale((
+            EN,
+            "hello = Hello, {$name}!\ncolour = Color\nlogin = Log in\n    .placeholder = Email\n    .title = Log in to your account",
+        ))
+        .with_locale((EN_GB, "colour = Colour"))
+        .with_namespaced_locale("settings", (EN, "title = Settings"));
+    use_init_i18n(|| config)
+}
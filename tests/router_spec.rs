@@ -0,0 +1,98 @@
+#![cfg(feature = "router")]
+
+mod common;
+use common::*;
+
+use dioxus_i18n::prelude::{
+    retranslate_route_slugs, translate_route_slugs, use_init_i18n, use_route_language, I18n,
+    I18nConfig, MissingTranslationPolicy,
+};
+use unic_langid::{langid, LanguageIdentifier};
+
+use std::{cell::RefCell, rc::Rc};
+
+#[test]
+fn route_language_uses_matching_locale() {
+    let languages = Rc::new(RefCell::new(vec![]));
+    let recorder = languages.clone();
+    test_hook(
+        || {
+            let i18n = i18n(MissingTranslationPolicy::Error);
+            use_route_language("es".to_string());
+            i18n
+        },
+        move |value, _| recorder.borrow_mut().push(value.language()),
+    );
+    assert_eq!(languages.borrow().last(), Some(&ES_ES));
+}
+
+#[test]
+fn slugs_are_translated() {
+    test_hook(
+        || i18n(MissingTranslationPolicy::Error),
+        |mut value, proxy| {
+            if value.language() == EN {
+                value.set_language(ES_ES);
+            }
+            proxy.assert(
+                translate_route_slugs("/settings/42?tab=1"),
+                "/ajustes/42?tab=1".to_string(),
+                "slugs_are_translated",
+            );
+        },
+    );
+}
+
+#[test]
+fn untranslated_slugs_ignore_missing_translation_policy() {
+    test_hook(
+        || {
+            i18n(MissingTranslationPolicy::PanicInDebug(Box::new(
+                MissingTranslationPolicy::Marker,
+            )))
+        },
+        |_, proxy| {
+            proxy.assert(
+                translate_route_slugs("/about/42"),
+                "/about/42".to_string(),
+                "untranslated_slugs_ignore_missing_translation_policy",
+            );
+        },
+    );
+}
+
+#[test]
+fn slugs_are_retranslated() {
+    test_hook(
+        || i18n(MissingTranslationPolicy::Marker),
+        |mut value, proxy| {
+            if value.language() == EN {
+                value.set_language(ES_ES);
+            }
+            proxy.assert(
+                (
+                    retranslate_route_slugs("/es-ES/ajustes/perfil/42", &EN),
+                    retranslate_route_slugs("/es-ES/ajustes/perfil/42", &FR),
+                ),
+                (
+                    "/es-ES/settings/profile/42".to_string(),
+                    "/es-ES/parametres/profile/42".to_string(),
+                ),
+                "slugs_are_retranslated",
+            );
+        },
+    );
+}
+
+const EN: LanguageIdentifier = langid!("en");
+const ES_ES: LanguageIdentifier = langid!("es-ES");
+const FR: LanguageIdentifier = langid!("fr");
+
+fn i18n(policy: MissingTranslationPolicy) -> I18n {
+    let config = I18nConfig::new(EN)
+        .with_locale((EN, "route-settings = settings\nroute-profile = profile"))
+        .with_locale((ES_ES, "route-settings = ajustes\nroute-profile = perfil"))
+        .with_locale((FR, "route-settings = parametres"))
+        .with_missing_translation_policy(policy);
+    use_init_i18n(|| config)
+}
@@ -0,0 +1,124 @@
+mod common;
+use common::*;
+
+use dioxus_i18n::prelude::{use_init_i18n, DioxusI18nError, I18n, I18nConfig};
+use unic_langid::{langid, LanguageIdentifier};
+
+#[test]
+fn added_locale_can_be_selected() {
+    test_hook(i18n, |mut value, proxy| {
+        if value.language() == EN {
+            value.add_locale((ES, "hello = Hola"));
+            value.set_language(ES);
+        }
+        proxy.assert(
+            (value.available_languages(), value.translate("hello")),
+            (vec![EN, EN_GB, ES], "Hola".to_string()),
+            "added_locale_can_be_selected",
+        );
+    });
+}
+
+#[test]
+fn adding_existing_locale_fails() {
+    test_hook(i18n, |mut value, proxy| {
+        proxy.assert(
+            matches!(
+                value.try_add_locale((EN, "hello = Hi")),
+                Err(DioxusI18nError::LocaleAlreadyExists(_))
+            ),
+            true,
+            "adding_existing_locale_fails",
+        );
+    });
+}
+
+#[test]
+fn replaced_locale_in_use_is_reloaded() {
+    test_hook(i18n, |mut value, proxy| {
+        if value.translate("hello") == "Hello" {
+            value.replace_locale((EN, "hello = Hi"));
+        }
+        proxy.assert(
+            value.translate("hello"),
+            "Hi".to_string(),
+            "replaced_locale_in_use_is_reloaded",
+        );
+    });
+}
+
+#[test]
+fn failed_replacement_leaves_locale_untouched() {
+    test_hook(i18n, |mut value, proxy| {
+        proxy.assert(
+            matches!(
+                value.try_replace_locale((EN, "hello = Hi\nbroken")),
+                Err(DioxusI18nError::ResourceParseFailed { .. })
+            ),
+            true,
+            "failed_replacement_leaves_locale_untouched: error",
+        );
+        proxy.assert(
+            value.translate("hello"),
+            "Hello".to_string(),
+            "failed_replacement_leaves_locale_untouched",
+        );
+    });
+}
+
+#[test]
+fn replacing_missing_locale_fails() {
+    test_hook(i18n, |mut value, proxy| {
+        proxy.assert(
+            matches!(
+                value.try_replace_locale((ES, "hello = Hola")),
+                Err(DioxusI18nError::LocaleNotFound(_))
+            ),
+            true,
+            "replacing_missing_locale_fails",
+        );
+    });
+}
+
+#[test]
+fn removed_locale_in_use_is_unloaded() {
+    test_hook(i18n, |mut value, proxy| {
+        if value.language() == EN {
+            value.set_language(EN_GB);
+        }
+        if value.available_languages().contains(&EN_GB) {
+            value.remove_locale(&EN_GB);
+        }
+        proxy.assert(
+            (value.available_languages(), value.translate("colour")),
+            (vec![EN], "Color".to_string()),
+            "removed_locale_in_use_is_unloaded",
+        );
+    });
+}
+
+#[test]
+fn fallback_cannot_be_removed() {
+    test_hook(i18n, |mut value, proxy| {
+        proxy.assert(
+            matches!(
+                value.try_remove_locale(&EN),
+                Err(DioxusI18nError::FallbackCannotBeRemoved(_))
+            ),
+            true,
+            "fallback_cannot_be_removed",
+        );
+    });
+}
+
+const EN: LanguageIdentifier = langid!("en");
+const EN_GB: LanguageIdentifier = langid!("en-GB");
+const ES: LanguageIdentifier = langid!("es");
+
+fn i18n() -> I18n {
+    let config = I18nConfig::new(EN)
+        .with_fallback(EN)
+        .with_locale((EN, "hello = Hello\ncolour = Color"))
+        .with_locale((EN_GB, "colour = Colour"));
+    use_init_i18n(|| config)
+}
@@ -0,0 +1,111 @@
+mod common;
+use common::*;
+
+use dioxus_i18n::{
+    prelude::{i18n, use_i18n_scope, use_init_i18n, I18n, I18nConfig},
+    t,
+};
+use unic_langid::{langid, LanguageIdentifier};
+
+#[test]
+fn scope_translates_into_its_own_language() {
+    test_hook(i18n_with_scope, |(parent, scope), proxy| {
+        proxy.assert(
+            scope.translate("hello"),
+            "Hola".to_string(),
+            "scope_translates_into_its_own_language[scope]",
+        );
+        proxy.assert(
+            parent.translate("hello"),
+            "Hello".to_string(),
+            "scope_translates_into_its_own_language[parent]",
+        );
+    });
+}
+
+#[test]
+fn macros_use_the_nearest_scope() {
+    test_hook(i18n_with_scope, |_, proxy| {
+        proxy.assert(i18n().language(), ES, "macros_use_the_nearest_scope[i18n]");
+        proxy.assert(
+            t!("hello"),
+            "Hola".to_string(),
+            "macros_use_the_nearest_scope[t]",
+        );
+    });
+}
+
+#[test]
+fn scope_language_change_leaves_parent_unchanged() {
+    test_hook(i18n_with_scope, |(parent, mut scope), proxy| {
+        if scope.language() == ES {
+            scope.set_language(FR);
+        }
+        proxy.assert(
+            scope.translate("hello"),
+            "Bonjour".to_string(),
+            "scope_language_change_leaves_parent_unchanged[scope]",
+        );
+        proxy.assert(
+            parent.language(),
+            EN,
+            "scope_language_change_leaves_parent_unchanged[parent]",
+        );
+    });
+}
+
+#[test]
+fn parent_override_reaches_scope() {
+    test_hook(i18n_with_scope, |(mut parent, scope), proxy| {
+        if scope.translate("hello") == "Hola" {
+            parent.set_override(ES, "hello", "¡Hola!");
+        }
+        proxy.assert(
+            scope.translate("hello"),
+            "¡Hola!".to_string(),
+            "parent_override_reaches_scope",
+        );
+    });
+}
+
+#[test]
+fn parent_locale_change_reaches_scope() {
+    test_hook(i18n_with_scope, |(mut parent, scope), proxy| {
+        if scope.translate("hello") == "Hola" {
+            parent.replace_locale((ES, "hello = Buenas"));
+        }
+        proxy.assert(
+            scope.translate("hello"),
+            "Buenas".to_string(),
+            "parent_locale_change_reaches_scope",
+        );
+    });
+}
+
+#[test]
+fn scope_locale_change_reaches_parent() {
+    test_hook(i18n_with_scope, |(parent, mut scope), proxy| {
+        if parent.translate("hello") == "Hello" {
+            scope.replace_locale((EN, "hello = Hi"));
+        }
+        proxy.assert(
+            (parent.translate("hello"), scope.translate("hello")),
+            ("Hi".to_string(), "Hola".to_string()),
+            "scope_locale_change_reaches_parent",
+        );
+    });
+}
+
+const EN: LanguageIdentifier = langid!("en");
+const ES: LanguageIdentifier = langid!("es");
+const FR: LanguageIdentifier = langid!("fr");
+
+fn i18n_with_scope() -> (I18n, I18n) {
+    let config = I18nConfig::new(EN)
+        .with_locale((EN, "hello = Hello"))
+        .with_locale((ES, "hello = Hola"))
+        .with_locale((FR, "hello = Bonjour"));
+    let parent = use_init_i18n(|| config);
+    let scope = use_i18n_scope(ES);
+    (parent, scope)
+}
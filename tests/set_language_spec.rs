@@ -0,0 +1,74 @@
+mod common;
+use common::*;
+
+use dioxus_i18n::prelude::{use_init_i18n, I18n, I18nConfig};
+use unic_langid::{langid, LanguageIdentifier};
+
+use std::{cell::RefCell, rc::Rc};
+
+#[test]
+fn failed_language_change_leaves_state_untouched() {
+    test_hook(i18n, |mut value, proxy| {
+        let changes = Rc::new(RefCell::new(0));
+        let recorder = changes.clone();
+        value.on_language_changed(move |_, _| *recorder.borrow_mut() += 1);
+
+        proxy.assert(
+            value.try_set_language(ES).is_err(),
+            true,
+            "failed_language_change_leaves_state_untouched: error",
+        );
+        proxy.assert(
+            (
+                value.language(),
+                value.translate("hello"),
+                *changes.borrow(),
+            ),
+            (EN, "Hello".to_string(), 0),
+            "failed_language_change_leaves_state_untouched",
+        );
+    });
+}
+
+#[test]
+fn failed_fallback_change_leaves_state_untouched() {
+    test_hook(i18n, |mut value, proxy| {
+        proxy.assert(
+            value.try_set_fallback_language(ES).is_err(),
+            true,
+            "failed_fallback_change_leaves_state_untouched: error",
+        );
+        proxy.assert(
+            (value.fallback_language(), value.translate("only-in-en")),
+            (Some(EN), "English".to_string()),
+            "failed_fallback_change_leaves_state_untouched",
+        );
+    });
+}
+
+#[test]
+fn successful_language_change_is_applied() {
+    test_hook(i18n, |mut value, proxy| {
+        if value.language() == EN {
+            value.set_language(FR);
+        }
+        proxy.assert(
+            (value.language(), value.translate("hello")),
+            (FR, "Bonjour".to_string()),
+            "successful_language_change_is_applied",
+        );
+    });
+}
+
+const EN: LanguageIdentifier = langid!("en");
+const ES: LanguageIdentifier = langid!("es");
+const FR: LanguageIdentifier = langid!("fr");
+
+fn i18n() -> I18n {
+    let config = I18nConfig::new(EN)
+        .with_fallback(EN)
+        .with_locale((EN, "hello = Hello\nonly-in-en = English"))
+        .with_locale((ES, "hello = Hola\nbroken"))
+        .with_locale((FR, "hello = Bonjour"));
+    use_init_i18n(|| config)
+}
@@ -0,0 +1,137 @@
+mod common;
+use common::*;
+
+use dioxus_i18n::{
+    prelude::{use_init_i18n, DioxusI18nError, I18n, I18nConfig},
+    t,
+};
+use unic_langid::{langid, LanguageIdentifier};
+
+#[test]
+fn translate_term() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value.translate("-brand-name"),
+            "Firefox".to_string(),
+            "translate_term[translate]",
+        );
+        proxy.assert(
+            t!("-brand-name"),
+            "Firefox".to_string(),
+            "translate_term[t]",
+        );
+    });
+}
+
+#[test]
+fn translate_term_attribute() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value.translate("-brand-name.gender"),
+            "masculine".to_string(),
+            "translate_term_attribute",
+        );
+    });
+}
+
+#[test]
+fn translate_term_from_fallback() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value.translate("-vendor"),
+            "Mozilla".to_string(),
+            "translate_term_from_fallback",
+        );
+    });
+}
+
+#[test]
+fn has_term() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(value.has_message("-brand-name"), true, "has_term[term]");
+        proxy.assert(
+            value.has_attribute("-brand-name.gender"),
+            true,
+            "has_term[attribute]",
+        );
+        proxy.assert(value.has_message("-missing"), false, "has_term[missing]");
+    });
+}
+
+#[test]
+fn translate_dotted_id_as_attribute() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value.translate("settings.profile.title"),
+            "Profile settings".to_string(),
+            "translate_dotted_id_as_attribute",
+        );
+    });
+}
+
+#[test]
+fn translate_dotted_id_as_message() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value.translate("settings.account.name"),
+            "Account name".to_string(),
+            "translate_dotted_id_as_message[two dots]",
+        );
+        proxy.assert(
+            value.translate("settings.language"),
+            "Language".to_string(),
+            "translate_dotted_id_as_message[one dot]",
+        );
+    });
+}
+
+#[test]
+fn fail_translate_missing_attribute_of_existing_message() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            matches!(
+                value.try_translate("login.placeholder"),
+                Err(DioxusI18nError::AttributeIdNotFound(_))
+            ),
+            true,
+            "fail_translate_missing_attribute_of_existing_message",
+        );
+    });
+}
+
+#[test]
+fn fail_translate_empty_id_segment() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value
+                .try_translate("settings..title")
+                .unwrap_err()
+                .to_string(),
+            "invalid message id: 'settings..title'".to_string(),
+            "fail_translate_empty_id_segment",
+        );
+    });
+}
+
+const EN: LanguageIdentifier = langid!("en");
+const ES: LanguageIdentifier = langid!("es");
+
+fn i18n() -> I18n {
+    let config = I18nConfig::new(ES)
+        .with_fallback(EN)
+        .with_locale((EN, "-vendor = Mozilla\n-brand-name = Firefox"))
+        .with_locale((
+            ES,
+            r#"-brand-name = Firefox
+    .gender = masculine
+settings-profile =
+    .title = Profile settings
+settings-account-name = Account name
+settings-language = Language
+login = Log in
+    .label = Email
+login-placeholder = Your email
+"#,
+        ));
+    use_init_i18n(|| config)
+}
@@ -0,0 +1,81 @@
+mod common;
+use common::*;
+
+use dioxus_i18n::{
+    prelude::{use_init_i18n, I18n, I18nConfig},
+    t_in,
+};
+use unic_langid::{langid, LanguageIdentifier};
+
+#[test]
+fn translate_in_leaves_selected_language_unchanged() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value.translate_in(&ES, "hello", None),
+            "Hola".to_string(),
+            "translate_in_leaves_selected_language_unchanged[translation]",
+        );
+        proxy.assert(
+            value.language(),
+            EN,
+            "translate_in_leaves_selected_language_unchanged[language]",
+        );
+    });
+}
+
+#[test]
+fn translate_in_uses_less_specific_locale() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value.translate_in(&langid!("es-MX"), "hello", None),
+            "Hola".to_string(),
+            "translate_in_uses_less_specific_locale",
+        );
+    });
+}
+
+#[test]
+fn translate_in_uses_fallback() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value.translate_in(&ES, "bye", None),
+            "Bye".to_string(),
+            "translate_in_uses_fallback",
+        );
+    });
+}
+
+#[test]
+fn translate_in_namespace() {
+    test_hook(i18n, |value, proxy| {
+        proxy.assert(
+            value.translate_in(&ES, "settings:title", None),
+            "Ajustes".to_string(),
+            "translate_in_namespace",
+        );
+    });
+}
+
+#[test]
+fn translate_in_macro() {
+    test_hook(i18n, |_, proxy| {
+        proxy.assert(
+            t_in!(&ES, "welcome", name: "Zaphod"),
+            "Bienvenido, \u{2068}Zaphod\u{2069}".to_string(),
+            "translate_in_macro",
+        );
+    });
+}
+
+const EN: LanguageIdentifier = langid!("en");
+const ES: LanguageIdentifier = langid!("es");
+
+fn i18n() -> I18n {
+    let config = I18nConfig::new(EN)
+        .with_fallback(EN)
+        .with_locale((EN, "hello = Hello\nbye = Bye\nwelcome = Welcome, {$name}"))
+        .with_locale((ES, "hello = Hola\nwelcome = Bienvenido, {$name}"))
+        .with_namespaced_locale("settings", (EN, "title = Settings"))
+        .with_namespaced_locale("settings", (ES, "title = Ajustes"));
+    use_init_i18n(|| config)
+}
@@ -199,7 +199,7 @@ fn fail_translate_with_invalid_attribute_key() {
         );
         proxy.assert(
             panic.ok().unwrap(),
-            "invalid message id: 'my_component.placeholder.invalid'".to_string(),
+            "message id not found for key: 'my_component-placeholder'".to_string(),
             "fail_translate_with_invalid_attribute_key",
         );
     });
@@ -245,6 +245,17 @@ fn language_can_be_set() {
     });
 }
 
+#[test]
+fn available_languages_are_sorted() {
+    test_hook(i18n_with_languages, |value, proxy| {
+        proxy.assert(
+            value.available_languages(),
+            vec![EN, IT, JP],
+            "available_languages_are_sorted",
+        );
+    });
+}
+
 #[test]
 fn no_default_fallback_language() {
     test_hook(i18n_from_static, |value, proxy| {
@@ -313,6 +324,14 @@ fn i18n_from_static() -> I18n {
     use_init_i18n(|| config)
 }
 
+fn i18n_with_languages() -> I18n {
+    let config = I18nConfig::new(EN)
+        .with_locale((JP, include_str!("./data/i18n/en.ftl")))
+        .with_locale((EN, include_str!("./data/i18n/en.ftl")))
+        .with_locale((IT, include_str!("./data/i18n/en.ftl")));
+    use_init_i18n(|| config)
+}
+
 fn i18n_from_static_with_fallback() -> I18n {
     let config = I18nConfig::new(EN)
         .with_locale((EN, include_str!("./data/i18n/en.ftl")))
@@ -0,0 +1,43 @@
+use dioxus_i18n::{fluent::FluentArgs, negotiate::parse_accept_language, prelude::*};
+use pretty_assertions::assert_eq;
+use unic_langid::{langid, LanguageIdentifier};
+
+#[test]
+fn translator_does_not_need_a_runtime() {
+    let translator = Translator::new(config());
+    assert_eq!(translator.language(), &EN);
+    assert_eq!(translator.translate("saved"), "Saved");
+}
+
+#[test]
+fn translator_uses_negotiated_language() {
+    let requested = parse_accept_language("es-ES, en;q=0.5");
+    let translator = Translator::new(config().with_negotiated_language(&requested));
+
+    let mut args = FluentArgs::new();
+    args.set("name", "Zaphod");
+
+    assert_eq!(translator.language(), &ES);
+    assert_eq!(
+        translator.translate_with_args("welcome", Some(&args)),
+        "Bienvenido, \u{2068}Zaphod\u{2069}"
+    );
+}
+
+#[test]
+fn translator_fails_with_invalid_key() {
+    let translator = Translator::new(config());
+    assert_eq!(
+        translator.try_translate("invalid").unwrap_err().to_string(),
+        "message id not found for key: 'invalid'"
+    );
+}
+
+const EN: LanguageIdentifier = langid!("en");
+const ES: LanguageIdentifier = langid!("es");
+
+fn config() -> I18nConfig {
+    I18nConfig::new(EN)
+        .with_locale((EN, "saved = Saved\nwelcome = Welcome, {$name}"))
+        .with_locale((ES, "saved = Guardado\nwelcome = Bienvenido, {$name}"))
+}
@@ -0,0 +1,97 @@
+use dioxus_i18n::prelude::*;
+use pretty_assertions::assert_eq;
+use unic_langid::{langid, LanguageIdentifier};
+
+use std::path::PathBuf;
+
+#[test]
+fn valid_config() {
+    assert!(config().validate().is_ok());
+}
+
+#[test]
+fn initial_language_with_less_specific_locale_is_valid() {
+    let config = I18nConfig::new(langid!("en-GB")).with_locale((EN, "hello = Hello"));
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn validate_reports_all_problems() {
+    let config = I18nConfig::new(FR)
+        .with_fallback(langid!("it"))
+        .with_locale((EN, "hello = Hello"))
+        .with_locale((DE, "hello = Hallo\nbroken"))
+        .with_locale((ES, PathBuf::from("./non-existing.ftl")))
+        .with_namespaced_locale("settings", (EN, "title = {"));
+
+    let Err(DioxusI18nError::InvalidConfig(errors)) = config.validate() else {
+        panic!("expected an invalid config");
+    };
+    let mut errors: Vec<_> = errors
+        .iter()
+        .map(|err| err.to_string().lines().next().unwrap().to_string())
+        .collect();
+    errors.sort();
+    assert_eq!(
+        errors,
+        vec![
+            "failed to parse static resource for de:",
+            "failed to parse static resource for en:",
+            "failed to read locale resource from path './non-existing.ftl': No such file or directory (os error 2)",
+            "fallback for \"it\" must have locale",
+            "language \"fr\" must have locale",
+        ]
+    );
+}
+
+#[test]
+fn strict_validation_fails_on_unused_broken_resource() {
+    let broken = || config().with_locale((DE, "hello = Hallo\nbroken"));
+    assert!(Translator::try_new(broken()).is_ok());
+    assert!(matches!(
+        Translator::try_new(broken().with_strict_validation()),
+        Err(DioxusI18nError::InvalidConfig(errors)) if errors.len() == 1
+    ));
+}
+
+#[test]
+fn strict_validation_keeps_broken_resource_with_lenient_parsing() {
+    let config = || {
+        config()
+            .with_locale((DE, "hello = Hallo\nbroken"))
+            .with_strict_validation()
+            .with_lenient_parsing()
+    };
+    assert!(config().validate().is_ok());
+    assert!(Translator::try_new(config()).is_ok());
+
+    let diagnostics: Vec<_> = config()
+        .resource_diagnostics()
+        .iter()
+        .map(|err| err.to_string().lines().next().unwrap().to_string())
+        .collect();
+    assert_eq!(diagnostics, vec!["failed to parse static resource for de:"]);
+}
+
+#[test]
+fn lenient_parsing_still_fails_on_unreadable_resource() {
+    let config = config()
+        .with_locale((ES, PathBuf::from("./non-existing.ftl")))
+        .with_lenient_parsing();
+    assert!(matches!(
+        config.validate(),
+        Err(DioxusI18nError::InvalidConfig(errors)) if errors.len() == 1
+    ));
+}
+
+const EN: LanguageIdentifier = langid!("en");
+const DE: LanguageIdentifier = langid!("de");
+const ES: LanguageIdentifier = langid!("es");
+const FR: LanguageIdentifier = langid!("fr");
+
+fn config() -> I18nConfig {
+    I18nConfig::new(EN)
+        .with_fallback(EN)
+        .with_locale((EN, "hello = Hello"))
+        .with_namespaced_locale("settings", (EN, "title = Settings"))
+}